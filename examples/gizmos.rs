@@ -0,0 +1,72 @@
+//! Shows color blindness simulation on gizmos, to check whether debug color-coding is legible
+//! Draws a few colored gizmo shapes, like the ones a debug overlay might use
+//!
+//! Gizmos are drawn during the main pass, so the simulation applies to them too
+//!
+//! Holding the Space key enables the simulation
+//! Pressing N cycles through the modes
+
+use bevy::{prelude::*, window::close_on_esc};
+use bevy_color_blindness::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        // add the plugin
+        .add_plugin(ColorBlindnessPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, close_on_esc)
+        .add_systems(Update, (change_mode, draw_gizmos))
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands
+        .spawn(Camera2dBundle::default())
+        .insert(ColorBlindnessCamera {
+            mode: ColorBlindnessMode::Deuteranopia,
+            enabled: false,
+            ..default()
+        });
+}
+
+/// draws shapes in the colors a debug overlay would commonly use
+fn draw_gizmos(mut gizmos: Gizmos, time: Res<Time>) {
+    let colors = [
+        // e.g. "ok", "warning", and "error"
+        Color::GREEN,
+        Color::YELLOW,
+        Color::RED,
+        Color::BLUE,
+        Color::ORANGE,
+        Color::PURPLE,
+    ];
+
+    for (i, color) in colors.into_iter().enumerate() {
+        let x = -250.0 + 100.0 * i as f32;
+        gizmos.circle_2d(Vec2::new(x, 100.0), 40.0, color);
+        gizmos.rect_2d(
+            Vec2::new(x, -50.0),
+            time.elapsed_seconds() + i as f32,
+            Vec2::splat(60.0),
+            color,
+        );
+        gizmos.line_2d(
+            Vec2::new(x - 40.0, -150.0),
+            Vec2::new(x + 40.0, -150.0),
+            color,
+        );
+    }
+}
+
+fn change_mode(input: Res<Input<KeyCode>>, mut cameras: Query<&mut ColorBlindnessCamera>) {
+    for mut camera in &mut cameras {
+        // cycle through the modes by pressing N
+        if input.just_pressed(KeyCode::N) {
+            camera.mode.cycle();
+            println!("Changed to {:?}", camera.mode);
+        }
+
+        camera.enabled = input.pressed(KeyCode::Space);
+    }
+}
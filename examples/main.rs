@@ -74,8 +74,8 @@ fn setup(
         .insert(ColorBlindnessCamera {
             mode: ColorBlindnessMode::Deuteranopia,
             enabled: false,
-        })
-        .insert(ColorBlindnessPostProcess::default());
+            ..default()
+        });
 }
 
 fn change_mode(input: Res<Input<KeyCode>>, mut cameras: Query<&mut ColorBlindnessCamera>) {
@@ -0,0 +1,113 @@
+//! Shows a texture next to a thumbnail of how it looks with color blindness,
+//! using `ColorBlindnessMaterial` instead of simulating the whole camera
+//!
+//! Pressing N cycles through the modes on the thumbnail
+
+use bevy::{
+    prelude::*,
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+    sprite::{Material2dPlugin, MaterialMesh2dBundle},
+    window::close_on_esc,
+};
+use bevy_color_blindness::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        // add the material
+        .add_plugin(Material2dPlugin::<ColorBlindnessMaterial>::default())
+        .add_systems(Startup, setup)
+        .add_systems(Update, close_on_esc)
+        .add_systems(Update, change_mode)
+        .run();
+}
+
+/// marks the thumbnail, and which mode it's showing
+#[derive(Component)]
+struct Thumbnail {
+    material: Handle<ColorBlindnessMaterial>,
+    mode: ColorBlindnessMode,
+}
+
+/// creates a texture with vertical stripes of color
+fn stripes() -> Image {
+    let colors = [
+        [204, 179, 153, 255],
+        [255, 0, 0, 255],
+        [0, 255, 0, 255],
+        [0, 0, 255, 255],
+        [255, 255, 0, 255],
+        [255, 0, 255, 255],
+    ];
+    let data = (0..colors.len())
+        .flat_map(|_| colors.iter().flatten().copied())
+        .collect();
+
+    Image::new(
+        Extent3d {
+            width: colors.len() as u32,
+            height: colors.len() as u32,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+    )
+}
+
+fn setup(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorBlindnessMaterial>>,
+) {
+    let texture = images.add(stripes());
+
+    // the original texture
+    commands.spawn(SpriteBundle {
+        texture: texture.clone(),
+        sprite: Sprite {
+            custom_size: Some(Vec2::splat(300.0)),
+            ..default()
+        },
+        transform: Transform::from_xyz(-100.0, 0.0, 0.0),
+        ..default()
+    });
+
+    // a smaller thumbnail, simulating color blindness
+    let mode = ColorBlindnessMode::Deuteranopia;
+    let material = materials.add(ColorBlindnessMaterial::new(texture, &mode));
+    commands.spawn((
+        MaterialMesh2dBundle {
+            mesh: meshes
+                .add(shape::Quad::new(Vec2::splat(150.0)).into())
+                .into(),
+            material: material.clone(),
+            transform: Transform::from_xyz(200.0, 0.0, 0.0),
+            ..default()
+        },
+        Thumbnail { material, mode },
+    ));
+
+    commands.spawn(Camera2dBundle::default());
+}
+
+fn change_mode(
+    input: Res<Input<KeyCode>>,
+    mut thumbnails: Query<&mut Thumbnail>,
+    mut materials: ResMut<Assets<ColorBlindnessMaterial>>,
+) {
+    // cycle through the modes by pressing N
+    if !input.just_pressed(KeyCode::N) {
+        return;
+    }
+
+    for mut thumbnail in &mut thumbnails {
+        thumbnail.mode.cycle();
+        println!("Changed to {:?}", thumbnail.mode);
+
+        if let Some(material) = materials.get_mut(&thumbnail.material) {
+            material.percentages = thumbnail.mode.percentages();
+        }
+    }
+}
@@ -0,0 +1,107 @@
+//! Shows the same scene in two windows, to monitor color blindness on a second screen
+//! The primary window uses normal vision, and the second window simulates color blindness
+//!
+//! Each window is rendered by its own camera, so the effect only applies to the second one
+//!
+//! Pressing N cycles through the modes
+
+use bevy::{prelude::*, render::camera::RenderTarget, window::WindowRef};
+use bevy_color_blindness::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        // add the plugin
+        .add_plugin(ColorBlindnessPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, change_mode)
+        .run();
+}
+
+/// set up a simple 3D scene
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // create a small world
+    commands.spawn(PbrBundle {
+        mesh: meshes.add(Mesh::from(shape::Plane::from_size(50.0))),
+        material: materials.add(Color::rgb(0.3, 0.5, 0.3).into()),
+        ..default()
+    });
+    let cube = meshes.add(Mesh::from(shape::Cube { size: 0.5 }));
+    commands.spawn(PbrBundle {
+        mesh: cube.clone(),
+        material: materials.add(Color::rgb(0.8, 0.7, 0.6).into()),
+        transform: Transform::from_xyz(0.0, 0.5, 0.0),
+        ..default()
+    });
+    commands.spawn(PbrBundle {
+        mesh: cube.clone(),
+        material: materials.add(Color::rgb(1.0, 0.0, 0.0).into()),
+        transform: Transform::from_xyz(2.0, 0.5, 0.0),
+        ..default()
+    });
+    commands.spawn(PbrBundle {
+        mesh: cube.clone(),
+        material: materials.add(Color::rgb(0.0, 1.0, 0.0).into()),
+        transform: Transform::from_xyz(3.0, 0.5, 0.0),
+        ..default()
+    });
+    commands.spawn(PbrBundle {
+        mesh: cube,
+        material: materials.add(Color::rgb(0.0, 0.0, 1.0).into()),
+        transform: Transform::from_xyz(4.0, 0.5, 0.0),
+        ..default()
+    });
+    commands.spawn(PointLightBundle {
+        point_light: PointLight {
+            intensity: 1500.0,
+            shadows_enabled: true,
+            ..default()
+        },
+        transform: Transform::from_xyz(4.0, 8.0, 4.0),
+        ..default()
+    });
+
+    let transform = Transform::from_xyz(-2.0, 2.5, 5.0).looking_at(Vec3::ZERO, Vec3::Y);
+
+    // the camera for the primary window shows the scene normally
+    commands.spawn(Camera3dBundle {
+        transform,
+        ..default()
+    });
+
+    // open a second window, with a camera simulating color blindness
+    let preview_window = commands
+        .spawn(Window {
+            title: "Color blindness preview".into(),
+            ..default()
+        })
+        .id();
+    commands
+        .spawn(Camera3dBundle {
+            transform,
+            camera: Camera {
+                target: RenderTarget::Window(WindowRef::Entity(preview_window)),
+                ..default()
+            },
+            ..default()
+        })
+        .insert(ColorBlindnessCamera {
+            mode: ColorBlindnessMode::Deuteranopia,
+            enabled: true,
+            ..default()
+        });
+}
+
+fn change_mode(input: Res<Input<KeyCode>>, mut cameras: Query<&mut ColorBlindnessCamera>) {
+    // input is received from either window
+    if input.just_pressed(KeyCode::N) {
+        for mut camera in &mut cameras {
+            camera.mode.cycle();
+            println!("Changed to {:?}", camera.mode);
+        }
+    }
+}
@@ -0,0 +1,61 @@
+//! Shows color blindness simulation on a 2D scene with an orthographic camera
+//! Shows four colored squares
+//!
+//! Holding the Space key enables the simulation
+//! Pressing N cycles through the modes
+
+use bevy::{prelude::*, window::close_on_esc};
+use bevy_color_blindness::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        // add the plugin
+        .add_plugin(ColorBlindnessPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, close_on_esc)
+        .add_systems(Update, change_mode)
+        .run();
+}
+
+/// set up a simple 2D scene
+fn setup(mut commands: Commands) {
+    let colors = [
+        Color::rgb(0.8, 0.7, 0.6),
+        Color::rgb(1.0, 0.0, 0.0),
+        Color::rgb(0.0, 1.0, 0.0),
+        Color::rgb(0.0, 0.0, 1.0),
+    ];
+    for (i, color) in colors.into_iter().enumerate() {
+        commands.spawn(SpriteBundle {
+            sprite: Sprite {
+                color,
+                custom_size: Some(Vec2::splat(100.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(-225.0 + 150.0 * i as f32, 0.0, 0.0),
+            ..default()
+        });
+    }
+
+    // create the camera, which uses an `OrthographicProjection`
+    commands
+        .spawn(Camera2dBundle::default())
+        .insert(ColorBlindnessCamera {
+            mode: ColorBlindnessMode::Deuteranopia,
+            enabled: false,
+            ..default()
+        });
+}
+
+fn change_mode(input: Res<Input<KeyCode>>, mut cameras: Query<&mut ColorBlindnessCamera>) {
+    for mut camera in &mut cameras {
+        // cycle through the modes by pressing N
+        if input.just_pressed(KeyCode::N) {
+            camera.mode.cycle();
+            println!("Changed to {:?}", camera.mode);
+        }
+
+        camera.enabled = input.pressed(KeyCode::Space);
+    }
+}
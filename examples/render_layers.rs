@@ -0,0 +1,216 @@
+//! Simulates color blindness only on one category of objects, using render layers
+//! Objects in the simulated category are rendered by a second camera into a texture,
+//! which is simulated and then drawn over the normal scene
+//!
+//! Only the highlighted category is affected by the simulation.
+//! Since each category is rendered separately, objects of one category don't hide
+//! objects of the other, so this is meant for auditing assets rather than gameplay.
+//! Every simulated category uses one of the 32 available render layers,
+//! and an extra camera and texture.
+//!
+//! Pressing Tab switches which category is simulated
+//! Pressing N cycles through the modes
+
+use bevy::{
+    core_pipeline::clear_color::ClearColorConfig,
+    prelude::*,
+    render::{
+        camera::RenderTarget,
+        render_resource::{
+            Extent3d, TextureDescriptor, TextureDimension, TextureFormat, TextureUsages,
+        },
+        view::RenderLayers,
+    },
+    window::close_on_esc,
+};
+use bevy_color_blindness::*;
+
+/// render layer the simulated category is moved to
+const SIMULATED_LAYER: u8 = 1;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        // add the plugin
+        .add_plugin(ColorBlindnessPlugin)
+        .init_resource::<SimulatedCategory>()
+        .add_systems(Startup, setup)
+        .add_systems(Update, close_on_esc)
+        .add_systems(
+            Update,
+            (change_mode, switch_category, assign_layers).chain(),
+        )
+        .run();
+}
+
+/// categories objects can belong to
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Default)]
+enum Category {
+    #[default]
+    Interactive,
+    Scenery,
+}
+
+/// which category is currently being simulated
+#[derive(Resource, Default)]
+struct SimulatedCategory(Category);
+
+/// set up a simple 3D scene, with objects of both categories
+fn setup(
+    mut commands: Commands,
+    windows: Query<&Window>,
+    mut images: ResMut<Assets<Image>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(Mesh::from(shape::Plane::from_size(50.0))),
+            material: materials.add(Color::rgb(0.3, 0.5, 0.3).into()),
+            ..default()
+        },
+        Category::Scenery,
+    ));
+    let cube = meshes.add(Mesh::from(shape::Cube { size: 0.5 }));
+    for (x, color, category) in [
+        (0.0, Color::rgb(0.8, 0.7, 0.6), Category::Scenery),
+        (2.0, Color::rgb(1.0, 0.0, 0.0), Category::Interactive),
+        (3.0, Color::rgb(0.0, 1.0, 0.0), Category::Interactive),
+        (4.0, Color::rgb(0.0, 0.0, 1.0), Category::Scenery),
+    ] {
+        commands.spawn((
+            PbrBundle {
+                mesh: cube.clone(),
+                material: materials.add(color.into()),
+                transform: Transform::from_xyz(x, 0.5, 0.0),
+                ..default()
+            },
+            category,
+        ));
+    }
+    commands.spawn(PointLightBundle {
+        point_light: PointLight {
+            intensity: 1500.0,
+            shadows_enabled: true,
+            ..default()
+        },
+        transform: Transform::from_xyz(4.0, 8.0, 4.0),
+        ..default()
+    });
+
+    // texture the simulated category is rendered into, the same size as the window
+    let window = windows.single();
+    let size = Extent3d {
+        width: window.resolution.physical_width(),
+        height: window.resolution.physical_height(),
+        ..default()
+    };
+    let mut image = Image {
+        texture_descriptor: TextureDescriptor {
+            label: None,
+            size,
+            dimension: TextureDimension::D2,
+            format: TextureFormat::Bgra8UnormSrgb,
+            mip_level_count: 1,
+            sample_count: 1,
+            usage: TextureUsages::TEXTURE_BINDING
+                | TextureUsages::COPY_DST
+                | TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        },
+        ..default()
+    };
+    image.resize(size);
+    let image = images.add(image);
+
+    let transform = Transform::from_xyz(-2.0, 2.5, 5.0).looking_at(Vec3::ZERO, Vec3::Y);
+
+    // main camera, rendering everything that isn't simulated with normal vision
+    commands.spawn(Camera3dBundle {
+        transform,
+        ..default()
+    });
+
+    // camera rendering only the simulated category, over a transparent background
+    commands.spawn((
+        Camera3dBundle {
+            transform,
+            camera: Camera {
+                order: -1,
+                target: RenderTarget::Image(image.clone()),
+                ..default()
+            },
+            camera_3d: Camera3d {
+                clear_color: ClearColorConfig::Custom(Color::NONE),
+                ..default()
+            },
+            ..default()
+        },
+        RenderLayers::layer(SIMULATED_LAYER),
+        ColorBlindnessCamera {
+            mode: ColorBlindnessMode::Deuteranopia,
+            enabled: true,
+            ..default()
+        },
+    ));
+
+    // draw the simulated texture over the whole window.
+    // the effect keeps alpha intact, so the normal scene shows through the background
+    commands.spawn(ImageBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        },
+        image: UiImage::new(image),
+        ..default()
+    });
+}
+
+fn change_mode(input: Res<Input<KeyCode>>, mut cameras: Query<&mut ColorBlindnessCamera>) {
+    for mut camera in &mut cameras {
+        // cycle through the modes by pressing N
+        if input.just_pressed(KeyCode::N) {
+            camera.mode.cycle();
+            println!("Changed to {:?}", camera.mode);
+        }
+    }
+}
+
+fn switch_category(input: Res<Input<KeyCode>>, mut simulated: ResMut<SimulatedCategory>) {
+    // switch which category is simulated by pressing Tab
+    if input.just_pressed(KeyCode::Tab) {
+        simulated.0 = match simulated.0 {
+            Category::Interactive => Category::Scenery,
+            Category::Scenery => Category::Interactive,
+        };
+        println!("Simulating {:?}", simulated.0);
+    }
+}
+
+/// moves the simulated category to its own render layer, and everything else back to the default one
+fn assign_layers(
+    mut commands: Commands,
+    simulated: Res<SimulatedCategory>,
+    objects: Query<(Entity, &Category), Added<Category>>,
+    all_objects: Query<(Entity, &Category)>,
+) {
+    let layer = |category: &Category| {
+        if *category == simulated.0 {
+            RenderLayers::layer(SIMULATED_LAYER)
+        } else {
+            RenderLayers::default()
+        }
+    };
+
+    if simulated.is_changed() {
+        for (entity, category) in &all_objects {
+            commands.entity(entity).insert(layer(category));
+        }
+    } else {
+        for (entity, category) in &objects {
+            commands.entity(entity).insert(layer(category));
+        }
+    }
+}
@@ -0,0 +1,59 @@
+//! Simulates color blindness on an image file, on the CPU, without opening a window
+//!
+//! Useful for batch processing UI mockups, or checking screenshots in CI:
+//!
+//! `cargo run --example simulate_image -- mockup.png deuteranopia mockup_deuteranopia.png`
+//!
+//! The mode is parsed with `ColorBlindnessMode`'s `FromStr` implementation,
+//! so any mode name works, ignoring case (e.g. `protanopia`, `Tritanomaly`, `monochromacy:0.2`)
+
+use std::{env, error::Error, process::ExitCode};
+
+use bevy::{
+    prelude::*,
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+};
+use bevy_color_blindness::*;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let [input, mode, output] = args.as_slice() else {
+        eprintln!("usage: simulate_image <input> <mode> <output>");
+        return ExitCode::FAILURE;
+    };
+
+    match run(input, mode, output) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(input: &str, mode: &str, output: &str) -> Result<(), Box<dyn Error>> {
+    let mode: ColorBlindnessMode = mode.parse()?;
+
+    // image files store sRGB values, so the simulation runs on their linear values, like on the GPU
+    let rgba = image::open(input)?.into_rgba8();
+    let (width, height) = rgba.dimensions();
+    let source = Image::new(
+        Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        rgba.into_raw(),
+        TextureFormat::Rgba8UnormSrgb,
+    );
+
+    let simulated = simulate_image(&mode, &source)?;
+
+    image::RgbaImage::from_raw(width, height, simulated.data)
+        .ok_or("simulated image has the wrong size")?
+        .save(output)?;
+    println!("Saved {output}, simulating {mode:?}");
+
+    Ok(())
+}
@@ -0,0 +1,135 @@
+//! Shows the same scene twice, side by side, using camera viewports
+//! The left half uses normal vision, and the right half simulates color blindness
+//!
+//! Pressing N cycles through the modes
+
+use bevy::{
+    core_pipeline::clear_color::ClearColorConfig, prelude::*, render::camera::Viewport,
+    window::close_on_esc,
+};
+use bevy_color_blindness::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        // add the plugin
+        .add_plugin(ColorBlindnessPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, close_on_esc)
+        .add_systems(Update, (change_mode, set_viewports))
+        .run();
+}
+
+/// marks which half of the window a camera renders to
+#[derive(Component)]
+struct Half {
+    right: bool,
+}
+
+/// set up a simple 3D scene
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // create a small world
+    commands.spawn(PbrBundle {
+        mesh: meshes.add(Mesh::from(shape::Plane::from_size(50.0))),
+        material: materials.add(Color::rgb(0.3, 0.5, 0.3).into()),
+        ..default()
+    });
+    let cube = meshes.add(Mesh::from(shape::Cube { size: 0.5 }));
+    for (x, color) in [
+        (0.0, Color::rgb(0.8, 0.7, 0.6)),
+        (2.0, Color::rgb(1.0, 0.0, 0.0)),
+        (3.0, Color::rgb(0.0, 1.0, 0.0)),
+        (4.0, Color::rgb(0.0, 0.0, 1.0)),
+    ] {
+        commands.spawn(PbrBundle {
+            mesh: cube.clone(),
+            material: materials.add(color.into()),
+            transform: Transform::from_xyz(x, 0.5, 0.0),
+            ..default()
+        });
+    }
+    commands.spawn(PointLightBundle {
+        point_light: PointLight {
+            intensity: 1500.0,
+            shadows_enabled: true,
+            ..default()
+        },
+        transform: Transform::from_xyz(4.0, 8.0, 4.0),
+        ..default()
+    });
+
+    let transform = Transform::from_xyz(-2.0, 2.5, 5.0).looking_at(Vec3::ZERO, Vec3::Y);
+
+    // left camera, with normal vision
+    commands.spawn((
+        Camera3dBundle {
+            transform,
+            ..default()
+        },
+        Half { right: false },
+    ));
+
+    // right camera, simulating color blindness only inside of its viewport
+    commands.spawn((
+        Camera3dBundle {
+            transform,
+            camera: Camera {
+                order: 1,
+                ..default()
+            },
+            camera_3d: Camera3d {
+                // don't clear what the left camera rendered
+                clear_color: ClearColorConfig::None,
+                ..default()
+            },
+            ..default()
+        },
+        ColorBlindnessCamera {
+            mode: ColorBlindnessMode::Deuteranopia,
+            enabled: true,
+            ..default()
+        },
+        Half { right: true },
+    ));
+}
+
+/// makes each camera cover half of the window
+fn set_viewports(windows: Query<&Window>, mut cameras: Query<(&mut Camera, &Half)>) {
+    let Ok(window) = windows.get_single() else {
+        return;
+    };
+    let size = UVec2::new(
+        window.resolution.physical_width() / 2,
+        window.resolution.physical_height(),
+    );
+
+    for (mut camera, half) in &mut cameras {
+        let viewport = Viewport {
+            physical_position: UVec2::new(if half.right { size.x } else { 0 }, 0),
+            physical_size: size,
+            ..default()
+        };
+        if camera
+            .viewport
+            .as_ref()
+            .map(|v| (v.physical_position, v.physical_size))
+            != Some((viewport.physical_position, viewport.physical_size))
+        {
+            camera.viewport = Some(viewport);
+        }
+    }
+}
+
+fn change_mode(input: Res<Input<KeyCode>>, mut cameras: Query<&mut ColorBlindnessCamera>) {
+    for mut camera in &mut cameras {
+        // cycle through the modes by pressing N
+        if input.just_pressed(KeyCode::N) {
+            camera.mode.cycle();
+            println!("Changed to {:?}", camera.mode);
+        }
+    }
+}
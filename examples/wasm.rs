@@ -0,0 +1,84 @@
+//! Shows color blindness simulation in the browser, running on WebGL2
+//! Shows four colored squares
+//!
+//! Build it with `cargo build --example wasm --target wasm32-unknown-unknown`,
+//! and run it with [`wasm-server-runner`](https://github.com/jakobhellermann/wasm-server-runner)
+//! or `wasm-bindgen` and any static file server.
+//!
+//! Holding the Space key enables the simulation
+//! Pressing N cycles through the modes
+//! Pressing H toggles HDR on the camera, to check both kinds of targets
+
+use bevy::prelude::*;
+use bevy_color_blindness::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                // render to the `<canvas id="bevy">` of the page, filling its parent
+                canvas: Some("#bevy".into()),
+                fit_canvas_to_parent: true,
+                // don't let the browser handle keys like Space, which would scroll the page
+                prevent_default_event_handling: true,
+                ..default()
+            }),
+            ..default()
+        }))
+        // add the plugin
+        .add_plugin(ColorBlindnessPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, change_mode)
+        .run();
+}
+
+/// set up a simple 2D scene
+fn setup(mut commands: Commands) {
+    let colors = [
+        Color::rgb(0.8, 0.7, 0.6),
+        Color::rgb(1.0, 0.0, 0.0),
+        Color::rgb(0.0, 1.0, 0.0),
+        Color::rgb(0.0, 0.0, 1.0),
+    ];
+    for (i, color) in colors.into_iter().enumerate() {
+        commands.spawn(SpriteBundle {
+            sprite: Sprite {
+                color,
+                custom_size: Some(Vec2::splat(100.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(-225.0 + 150.0 * i as f32, 0.0, 0.0),
+            ..default()
+        });
+    }
+
+    commands
+        .spawn(Camera2dBundle::default())
+        .insert(ColorBlindnessCamera {
+            mode: ColorBlindnessMode::Deuteranopia,
+            enabled: false,
+            ..default()
+        });
+}
+
+fn change_mode(
+    input: Res<Input<KeyCode>>,
+    mut cameras: Query<(&mut Camera, &mut ColorBlindnessCamera)>,
+) {
+    for (mut camera, mut color_blindness) in &mut cameras {
+        // cycle through the modes by pressing N
+        if input.just_pressed(KeyCode::N) {
+            color_blindness.mode.cycle();
+            // `println!` doesn't show up in the browser console
+            info!("Changed to {:?}", color_blindness.mode);
+        }
+
+        // toggle between a `Rgba16Float` and a `Rgba8UnormSrgb` main texture by pressing H
+        if input.just_pressed(KeyCode::H) {
+            camera.hdr = !camera.hdr;
+            info!("HDR: {}", camera.hdr);
+        }
+
+        color_blindness.enabled = input.pressed(KeyCode::Space);
+    }
+}
@@ -6,6 +6,21 @@ struct Percentages {
     blue: vec3<f32>,
 };
 
+struct Settings {
+    percentages: Percentages,
+    // 0: simulation, 1: residual, 2: missing edges, 3: emphasize, 4: overlay
+    visualize: u32,
+    // area covered by the camera's viewport, as (min.x, min.y, max.x, max.y) in uv coordinates
+    viewport: vec4<f32>,
+    // 0: hard clamp, 1: soft rolloff, 2: normalize
+    clamping: u32,
+    // 1 to draw the calibration swatches
+    calibration: u32,
+    // linear color of the difference overlay, and how strongly differences show up in it
+    overlay_color: vec4<f32>,
+    overlay_scale: f32,
+};
+
 @group(0) @binding(0)
 var texture: texture_2d<f32>;
 
@@ -13,7 +28,113 @@ var texture: texture_2d<f32>;
 var our_sampler: sampler;
 
 @group(0) @binding(2)
-var<uniform> p: Percentages;
+var<uniform> settings: Settings;
+
+// Applies the percentages to a color
+fn simulate(c: vec3<f32>) -> vec3<f32> {
+    let p = settings.percentages;
+    return vec3<f32>(
+        c.r * p.red.x + c.g * p.red.y + c.b * p.red.z,
+        c.r * p.green.x + c.g * p.green.y + c.b * p.green.z,
+        c.r * p.blue.x + c.g * p.blue.y + c.b * p.blue.z,
+    );
+}
+
+// Strength of the edges around `uv` which are visible in the original image,
+// but disappear in the simulated one
+fn missing_edges(uv: vec2<f32>) -> f32 {
+    let texel = 1.0 / vec2<f32>(textureDimensions(texture));
+
+    // Sobel filter over the 3x3 neighbourhood, on both images
+    var original_x = vec3<f32>(0.0);
+    var original_y = vec3<f32>(0.0);
+    var simulated_x = vec3<f32>(0.0);
+    var simulated_y = vec3<f32>(0.0);
+    for (var y = -1; y <= 1; y += 1) {
+        for (var x = -1; x <= 1; x += 1) {
+            // explicit level, since this can run in non-uniform control flow
+            let c = textureSampleLevel(texture, our_sampler, uv + vec2<f32>(f32(x), f32(y)) * texel, 0.0).rgb;
+            let s = simulate(c);
+            let weight_x = f32(x) * f32(2 - abs(y));
+            let weight_y = f32(y) * f32(2 - abs(x));
+            original_x += c * weight_x;
+            original_y += c * weight_y;
+            simulated_x += s * weight_x;
+            simulated_y += s * weight_y;
+        }
+    }
+
+    let original = sqrt(dot(original_x, original_x) + dot(original_y, original_y));
+    let simulated = sqrt(dot(simulated_x, simulated_x) + dot(simulated_y, simulated_y));
+    return max(original - simulated, 0.0);
+}
+
+// Brings values that are out of range back into 0..1, according to `settings.clamping`.
+// With hard clamping, HDR targets keep values above 1
+fn clamp_color(c: vec3<f32>) -> vec3<f32> {
+    let positive = max(c, vec3<f32>(0.0));
+
+    if settings.clamping == 1u {
+        // Values above the knee are smoothly compressed so they approach 1 without reaching it
+        let knee = 0.8;
+        let over = max(positive - knee, vec3<f32>(0.0));
+        let compressed = knee + (1.0 - knee) * (1.0 - exp(-over / (1.0 - knee)));
+        return select(positive, compressed, positive > vec3<f32>(knee));
+    }
+
+    if settings.clamping == 2u {
+        // Scale the whole color down, preserving the ratios between channels
+        return positive / max(1.0, max(positive.r, max(positive.g, positive.b)));
+    }
+
+#ifdef HDR
+    // HDR targets can store values above 1, so only negative values are out of range
+    return positive;
+#else
+    return min(positive, vec3<f32>(1.0));
+#endif
+}
+
+// Converts an sRGB encoded channel to linear, to match the values in the texture
+fn srgb_to_linear(c: vec3<f32>) -> vec3<f32> {
+    let low = c / 12.92;
+    let high = pow((c + 0.055) / 1.055, vec3<f32>(2.4));
+    return select(high, low, c <= vec3<f32>(0.04045));
+}
+
+// Known sRGB colors shown by the calibration swatches
+fn swatch_color(index: u32) -> vec3<f32> {
+    switch index {
+        case 0u: { return vec3<f32>(1.0, 0.0, 0.0); }
+        case 1u: { return vec3<f32>(0.0, 1.0, 0.0); }
+        case 2u: { return vec3<f32>(0.0, 0.0, 1.0); }
+        case 3u: { return vec3<f32>(1.0, 1.0, 0.0); }
+        case 4u: { return vec3<f32>(0.0, 1.0, 1.0); }
+        case 5u: { return vec3<f32>(1.0, 0.0, 1.0); }
+        case 6u: { return vec3<f32>(1.0, 1.0, 1.0); }
+        default: { return vec3<f32>(0.5, 0.5, 0.5); }
+    }
+}
+
+// Color of the calibration swatch covering `uv`, with an alpha of 1, or 0 if no swatch covers it
+fn calibration_swatch(uv: vec2<f32>) -> vec4<f32> {
+    let size = settings.viewport.zw - settings.viewport.xy;
+    // position inside of the viewport, from 0 to 1
+    let local = (uv - settings.viewport.xy) / size;
+    // a row of 8 swatches, taking the bottom 1/16th of the viewport
+    if local.y < 15.0 / 16.0 {
+        return vec4<f32>(0.0);
+    }
+
+    let cell = local.x * 8.0;
+    let index = min(u32(cell), 7u);
+    let reference = srgb_to_linear(swatch_color(index));
+    // the left half is left unchanged, the right half is simulated
+    if fract(cell) < 0.5 {
+        return vec4<f32>(reference, 1.0);
+    }
+    return vec4<f32>(clamp_color(simulate(reference)), 1.0);
+}
 
 @fragment
 fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
@@ -22,10 +143,45 @@ fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
 
     var c = textureSample(texture, our_sampler, uv);
 
-    return vec4<f32>(
-        c.r * p.red.x + c.g * p.red.y + c.b * p.red.z,
-        c.r * p.green.x + c.g * p.green.y + c.b * p.green.z,
-        c.r * p.blue.x + c.g * p.blue.y + c.b * p.blue.z,
-        c.a
-    );
+    // Leave the parts of the target outside of the camera's viewport untouched
+    if any(uv < settings.viewport.xy) || any(uv > settings.viewport.zw) {
+        return c;
+    }
+
+    if settings.calibration == 1u {
+        let swatch = calibration_swatch(uv);
+        if swatch.a > 0.0 {
+            return vec4<f32>(swatch.rgb, c.a);
+        }
+    }
+
+    let simulated = simulate(c.rgb);
+
+    if settings.visualize == 1u {
+        // The information the viewer can't perceive
+        return vec4<f32>(clamp_color(abs(c.rgb - simulated)), c.a);
+    }
+
+    if settings.visualize == 2u {
+        // Highlight the boundaries that only color was conveying over a dimmed simulation
+        let edges = clamp(missing_edges(uv) * 4.0, 0.0, 1.0);
+        let highlighted = mix(clamp_color(simulated) * 0.5, vec3<f32>(1.0, 0.0, 1.0), edges);
+        return vec4<f32>(highlighted, c.a);
+    }
+
+    if settings.visualize == 3u {
+        // Push the original colors further in the directions the viewer can't perceive,
+        // exaggerating the information they lose
+        let emphasized = c.rgb + (c.rgb - simulated) * 2.0;
+        return vec4<f32>(clamp_color(emphasized), c.a);
+    }
+
+    if settings.visualize == 4u {
+        // Tint the original image where the simulation differs from it
+        let difference = length(c.rgb - simulated);
+        let amount = clamp(difference * settings.overlay_scale, 0.0, 1.0) * settings.overlay_color.a;
+        return vec4<f32>(clamp_color(mix(c.rgb, settings.overlay_color.rgb, amount)), c.a);
+    }
+
+    return vec4<f32>(clamp_color(simulated), c.a);
 }
@@ -0,0 +1,30 @@
+#import bevy_sprite::mesh2d_vertex_output MeshVertexOutput
+
+struct Percentages {
+    red: vec3<f32>,
+    green: vec3<f32>,
+    blue: vec3<f32>,
+};
+
+@group(1) @binding(0)
+var<uniform> percentages: Percentages;
+
+@group(1) @binding(1)
+var texture: texture_2d<f32>;
+
+@group(1) @binding(2)
+var our_sampler: sampler;
+
+@fragment
+fn fragment(mesh: MeshVertexOutput) -> @location(0) vec4<f32> {
+    let c = textureSample(texture, our_sampler, mesh.uv);
+    let p = percentages;
+
+    let simulated = vec3<f32>(
+        c.r * p.red.x + c.g * p.red.y + c.b * p.red.z,
+        c.r * p.green.x + c.g * p.green.y + c.b * p.green.z,
+        c.r * p.blue.x + c.g * p.blue.y + c.b * p.blue.z,
+    );
+
+    return vec4<f32>(clamp(simulated, vec3<f32>(0.0), vec3<f32>(1.0)), c.a);
+}
@@ -0,0 +1,85 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{ColorBlindnessCamera, ColorBlindnessMode, ColorBlindnessPreview};
+
+/// Extension trait adding color blindness helpers to [`Commands`].
+///
+/// These are thin wrappers over mutating [`ColorBlindnessCamera`], useful in places
+/// where querying for the camera would be overkill, like menu button handlers:
+///
+/// ```rust,no_run
+/// # use bevy::prelude::*;
+/// # use bevy_color_blindness::*;
+/// #[derive(Component)]
+/// struct ModeButton(ColorBlindnessMode);
+///
+/// fn mode_buttons(
+///     mut commands: Commands,
+///     buttons: Query<(&Interaction, &ModeButton), Changed<Interaction>>,
+///     cameras: Query<Entity, With<ColorBlindnessCamera>>,
+/// ) {
+///     for (interaction, button) in &buttons {
+///         if *interaction == Interaction::Clicked {
+///             for camera in &cameras {
+///                 commands.set_color_blindness(camera, button.0.clone());
+///             }
+///         }
+///     }
+/// }
+/// ```
+pub trait ColorBlindnessCommandsExt {
+    /// Queues setting the [`ColorBlindnessMode`] of the [`ColorBlindnessCamera`] on `entity`.
+    ///
+    /// Does nothing if `entity` doesn't have a [`ColorBlindnessCamera`] when the command is applied.
+    fn set_color_blindness(&mut self, entity: Entity, mode: ColorBlindnessMode);
+
+    /// Queues enabling `mode` on the [`ColorBlindnessCamera`] on `entity` for `duration`,
+    /// after which the camera goes back to its previous `mode` and `enabled` state.
+    ///
+    /// Previewing again before the previous preview ends restarts the timer,
+    /// and still restores the state from before the first preview.
+    /// See [`ColorBlindnessPreview`].
+    fn preview_color_blindness(
+        &mut self,
+        entity: Entity,
+        mode: ColorBlindnessMode,
+        duration: Duration,
+    );
+}
+
+impl<'w, 's> ColorBlindnessCommandsExt for Commands<'w, 's> {
+    fn set_color_blindness(&mut self, entity: Entity, mode: ColorBlindnessMode) {
+        self.add(move |world: &mut World| {
+            if let Some(mut camera) = world.get_mut::<ColorBlindnessCamera>(entity) {
+                camera.mode = mode;
+            }
+        });
+    }
+
+    fn preview_color_blindness(
+        &mut self,
+        entity: Entity,
+        mode: ColorBlindnessMode,
+        duration: Duration,
+    ) {
+        self.add(move |world: &mut World| {
+            let Some(camera) = world.get::<ColorBlindnessCamera>(entity) else {
+                return;
+            };
+            let preview = match world.get::<ColorBlindnessPreview>(entity) {
+                // keep restoring to the state before any preview started
+                Some(previous) => previous.restarted(duration),
+                None => ColorBlindnessPreview::new(camera, duration),
+            };
+
+            let mut entity = world.entity_mut(entity);
+            entity.insert(preview);
+            if let Some(mut camera) = entity.get_mut::<ColorBlindnessCamera>() {
+                camera.mode = mode;
+                camera.enabled = true;
+            }
+        });
+    }
+}
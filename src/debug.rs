@@ -0,0 +1,234 @@
+use bevy::{app::PluginGroupBuilder, prelude::*};
+
+use crate::{ColorBlindnessCamera, ColorBlindnessPlugin, Visualize};
+
+/// Group of plugins to get color blindness simulation working with controls and an on-screen label.
+///
+/// Includes:
+/// - [`ColorBlindnessPlugin`]
+/// - [`ColorBlindnessDebugControlsPlugin`]
+/// - [`ColorBlindnessModeLabelPlugin`]
+///
+/// Meant for development, in the same spirit as [`DefaultPlugins`].
+/// Only [`ColorBlindnessPlugin`] is needed to simulate color blindness without the extras.
+///
+/// ```rust,no_run
+/// # use bevy::prelude::*;
+/// # use bevy_color_blindness::*;
+/// App::new()
+///     .add_plugins(DefaultPlugins)
+///     .add_plugins(ColorBlindnessDevPlugins)
+///     .run();
+/// ```
+pub struct ColorBlindnessDevPlugins;
+
+impl PluginGroup for ColorBlindnessDevPlugins {
+    fn build(self) -> PluginGroupBuilder {
+        PluginGroupBuilder::start::<Self>()
+            .add(ColorBlindnessPlugin)
+            .add(ColorBlindnessDebugControlsPlugin)
+            .add(ColorBlindnessModeLabelPlugin)
+    }
+}
+
+/// Plugin adding keyboard and gamepad controls for every [`ColorBlindnessCamera`].
+///
+/// The keys can be changed through the [`ColorBlindnessDebugKeys`] resource,
+/// and the gamepad buttons through the [`ColorBlindnessDebugGamepad`] resource.
+pub struct ColorBlindnessDebugControlsPlugin;
+
+impl Plugin for ColorBlindnessDebugControlsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ColorBlindnessDebugKeys>()
+            .init_resource::<ColorBlindnessDebugGamepad>()
+            .add_systems(Update, (debug_controls, debug_gamepad_controls));
+    }
+}
+
+/// Keys used by [`ColorBlindnessDebugControlsPlugin`].
+#[derive(Resource, Clone, Debug)]
+pub struct ColorBlindnessDebugKeys {
+    /// Cycles through the modes
+    ///
+    /// Defaults to `KeyCode::N`
+    pub cycle: KeyCode,
+    /// Toggles the simulation on and off
+    ///
+    /// Defaults to `KeyCode::Space`
+    pub toggle: KeyCode,
+    /// Increases the severity by `severity_step`, see [`ColorBlindnessCamera::cycle_severity`]
+    ///
+    /// Defaults to `KeyCode::BracketRight`
+    pub severity_up: KeyCode,
+    /// Decreases the severity by `severity_step`, see [`ColorBlindnessCamera::cycle_severity`]
+    ///
+    /// Defaults to `KeyCode::BracketLeft`
+    pub severity_down: KeyCode,
+    /// How much the severity keys change the severity by
+    ///
+    /// Defaults to `0.1`
+    pub severity_step: f32,
+    /// Switches between [`Visualize::Simulation`] and [`Visualize::Emphasize`]
+    ///
+    /// Defaults to `KeyCode::E`
+    pub emphasize: KeyCode,
+    /// Toggles the calibration swatches, see [`ColorBlindnessCamera::calibration`]
+    ///
+    /// Defaults to `KeyCode::C`
+    pub calibration: KeyCode,
+}
+
+impl Default for ColorBlindnessDebugKeys {
+    fn default() -> Self {
+        Self {
+            cycle: KeyCode::N,
+            toggle: KeyCode::Space,
+            severity_up: KeyCode::BracketRight,
+            severity_down: KeyCode::BracketLeft,
+            severity_step: 0.1,
+            emphasize: KeyCode::E,
+            calibration: KeyCode::C,
+        }
+    }
+}
+
+/// Gamepad buttons used by [`ColorBlindnessDebugControlsPlugin`].
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_color_blindness::*;
+/// // only listen to the first gamepad, and cycle with the right bumper
+/// let gamepad = ColorBlindnessDebugGamepad {
+///     gamepad: Some(Gamepad::new(0)),
+///     cycle: GamepadButtonType::RightTrigger,
+///     ..default()
+/// };
+/// ```
+#[derive(Resource, Clone, Debug)]
+pub struct ColorBlindnessDebugGamepad {
+    /// Gamepad to listen to, or `None` to listen to every connected gamepad
+    ///
+    /// Defaults to `None`
+    pub gamepad: Option<Gamepad>,
+    /// Cycles through the modes
+    ///
+    /// Defaults to `GamepadButtonType::LeftThumb`, pressing the left stick
+    pub cycle: GamepadButtonType,
+    /// Toggles the simulation on and off
+    ///
+    /// Defaults to `GamepadButtonType::RightThumb`, pressing the right stick
+    pub toggle: GamepadButtonType,
+}
+
+impl Default for ColorBlindnessDebugGamepad {
+    fn default() -> Self {
+        Self {
+            gamepad: None,
+            cycle: GamepadButtonType::LeftThumb,
+            toggle: GamepadButtonType::RightThumb,
+        }
+    }
+}
+
+fn debug_gamepad_controls(
+    gamepads: Res<Gamepads>,
+    buttons: Res<Input<GamepadButton>>,
+    config: Res<ColorBlindnessDebugGamepad>,
+    mut cameras: Query<&mut ColorBlindnessCamera>,
+) {
+    let just_pressed = |button_type| {
+        gamepads
+            .iter()
+            .filter(|gamepad| config.gamepad.map_or(true, |config| config == *gamepad))
+            .any(|gamepad| buttons.just_pressed(GamepadButton::new(gamepad, button_type)))
+    };
+    let cycle = just_pressed(config.cycle);
+    let toggle = just_pressed(config.toggle);
+
+    for mut camera in &mut cameras {
+        if cycle {
+            camera.mode.cycle();
+        }
+        if toggle {
+            camera.enabled = !camera.enabled;
+        }
+    }
+}
+
+fn debug_controls(
+    input: Res<Input<KeyCode>>,
+    keys: Res<ColorBlindnessDebugKeys>,
+    mut cameras: Query<&mut ColorBlindnessCamera>,
+) {
+    for mut camera in &mut cameras {
+        if input.just_pressed(keys.cycle) {
+            camera.mode.cycle();
+        }
+        if input.just_pressed(keys.toggle) {
+            camera.enabled = !camera.enabled;
+        }
+        if input.just_pressed(keys.severity_up) {
+            camera.cycle_severity(keys.severity_step);
+        }
+        if input.just_pressed(keys.severity_down) {
+            camera.cycle_severity(-keys.severity_step);
+        }
+        if input.just_pressed(keys.emphasize) {
+            camera.visualize = match camera.visualize {
+                Visualize::Emphasize => Visualize::Simulation,
+                _ => Visualize::Emphasize,
+            };
+        }
+        if input.just_pressed(keys.calibration) {
+            camera.calibration = !camera.calibration;
+        }
+    }
+}
+
+/// Plugin showing the current mode of the first [`ColorBlindnessCamera`] in the top left corner.
+pub struct ColorBlindnessModeLabelPlugin;
+
+impl Plugin for ColorBlindnessModeLabelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, spawn_label)
+            .add_systems(Update, update_label);
+    }
+}
+
+/// Marker for the text spawned by [`ColorBlindnessModeLabelPlugin`].
+#[derive(Component)]
+pub struct ColorBlindnessModeLabel;
+
+fn spawn_label(mut commands: Commands) {
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 24.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        ),
+        ColorBlindnessModeLabel,
+    ));
+}
+
+fn update_label(
+    cameras: Query<Ref<ColorBlindnessCamera>>,
+    mut labels: Query<&mut Text, With<ColorBlindnessModeLabel>>,
+) {
+    let Some(camera) = cameras.iter().next() else {
+        return;
+    };
+
+    for mut label in &mut labels {
+        if !camera.is_changed() && !label.is_added() {
+            continue;
+        }
+        label.sections[0].value = if camera.enabled {
+            format!("{:?} ({:.0}%)", camera.mode, camera.severity * 100.0)
+        } else {
+            format!("{:?} (disabled)", camera.mode)
+        };
+    }
+}
@@ -0,0 +1,175 @@
+use bevy::{
+    asset::LoadState,
+    core_pipeline::{core_2d, core_3d},
+    prelude::*,
+    render::{render_graph::RenderGraph, RenderApp},
+};
+
+use crate::{graph, ColorBlindnessCamera, ColorBlindnessShader};
+
+/// Plugin checking for common setup mistakes which make the simulation silently do nothing,
+/// and logging a summary of what it finds.
+///
+/// Checks, once the app has started, that:
+/// - there is at least one [`ColorBlindnessCamera`], and at least one of them is enabled
+/// - at most one camera with the effect active renders to each target,
+///   since overlapping cameras (like a UI camera over a 3D one) would apply it twice
+/// - the pass has been added to the 2d and 3d render graphs, which requires
+///   [`ColorBlindnessPlugin`](crate::ColorBlindnessPlugin) to be added after Bevy's render plugins
+///
+/// It also warns if the simulation shader fails to load, and if enabled cameras have spent the first
+/// few seconds without the effect ever changing the image, explains how to turn it on.
+/// Cameras which are all disabled are already warned about by the plugin itself.
+///
+/// Only available with the `diagnostics` feature.
+///
+/// ```rust,no_run
+/// # use bevy::prelude::*;
+/// # use bevy_color_blindness::*;
+/// App::new()
+///     .add_plugins(DefaultPlugins)
+///     .add_plugin(ColorBlindnessPlugin)
+///     .add_plugin(ColorBlindnessDiagnosticsPlugin)
+///     .run();
+/// ```
+pub struct ColorBlindnessDiagnosticsPlugin;
+
+impl Plugin for ColorBlindnessDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PostStartup, check_cameras)
+            .add_systems(Update, (check_shader, check_identity));
+    }
+
+    fn finish(&self, app: &mut App) {
+        // graphs are set up during `build`, so by now every plugin has had a chance to add the node
+        let Ok(render_app) = app.get_sub_app(RenderApp) else {
+            info!("Color blindness diagnostics: no render app, the simulation is disabled");
+            return;
+        };
+        let Some(render_graph) = render_app.world.get_resource::<RenderGraph>() else {
+            return;
+        };
+
+        // with `core_graphs(false)`, the node is added to custom graphs instead
+        let in_custom_graph = render_graph.iter_sub_graphs().any(|(name, sub_graph)| {
+            name != core_2d::graph::NAME
+                && name != core_3d::graph::NAME
+                && sub_graph
+                    .get_node_state(graph::node::COLOR_BLINDNESS)
+                    .is_ok()
+        });
+        if in_custom_graph {
+            return;
+        }
+
+        for name in [core_2d::graph::NAME, core_3d::graph::NAME] {
+            let has_node = render_graph.get_sub_graph(name).map_or(false, |sub_graph| {
+                sub_graph
+                    .get_node_state(graph::node::COLOR_BLINDNESS)
+                    .is_ok()
+            });
+            if !has_node {
+                warn!("Color blindness diagnostics: the pass is missing from the `{name}` render graph, make sure `ColorBlindnessPlugin` is added after `DefaultPlugins`");
+            }
+        }
+    }
+}
+
+/// checks the cameras spawned during startup, and logs every problem found
+fn check_cameras(cameras: Query<(Entity, Option<&Camera>, &ColorBlindnessCamera)>) {
+    let mut problems = Vec::new();
+
+    if cameras.is_empty() {
+        problems.push("no entity has a `ColorBlindnessCamera`".to_string());
+    } else if cameras.iter().all(|(_, _, camera)| !camera.enabled) {
+        problems.push(
+            "every `ColorBlindnessCamera` is disabled, set `enabled` to see the simulation"
+                .to_string(),
+        );
+    }
+
+    let mut targets: Vec<(&_, Vec<Entity>)> = Vec::new();
+    for (entity, camera, color_blindness) in &cameras {
+        let Some(camera) = camera else {
+            problems.push(format!(
+                "{entity:?} has a `ColorBlindnessCamera` but no `Camera`"
+            ));
+            continue;
+        };
+        if !camera.is_active || !color_blindness.is_effect_active() {
+            continue;
+        }
+        match targets
+            .iter_mut()
+            .find(|(target, _)| **target == camera.target)
+        {
+            Some((_, entities)) => entities.push(entity),
+            None => targets.push((&camera.target, vec![entity])),
+        }
+    }
+    for (target, entities) in targets {
+        if entities.len() > 1 {
+            problems.push(format!(
+                "cameras {entities:?} all render to {target:?} with the effect active, so it may be applied more than once"
+            ));
+        }
+    }
+
+    if problems.is_empty() {
+        info!("Color blindness diagnostics: no problems found");
+    } else {
+        warn!(
+            "Color blindness diagnostics found {} problem(s):\n- {}",
+            problems.len(),
+            problems.join("\n- ")
+        );
+    }
+}
+
+/// warns once if the simulation shader failed to load
+fn check_shader(
+    mut reported: Local<bool>,
+    asset_server: Res<AssetServer>,
+    shader: Option<Res<ColorBlindnessShader>>,
+) {
+    let Some(shader) = shader else {
+        return;
+    };
+    if *reported {
+        return;
+    }
+
+    if asset_server.get_load_state(&shader.0) == LoadState::Failed {
+        warn!("Color blindness diagnostics: the simulation shader failed to load, make sure it's in your assets folder");
+        *reported = true;
+    }
+}
+
+/// how long cameras can go without the effect changing the image before `check_identity` explains why
+const IDENTITY_NUDGE_DELAY: f32 = 3.0;
+
+/// logs once if there are enabled cameras, but none of them has changed the image for a few seconds,
+/// e.g. because the mode is `Normal` or the severity is `0.0`
+fn check_identity(
+    mut state: Local<(f32, bool)>,
+    time: Res<Time>,
+    cameras: Query<&ColorBlindnessCamera>,
+) {
+    let (elapsed, done) = &mut *state;
+    // all disabled cameras are warned about by `ColorBlindnessPlugin`
+    if *done || !cameras.iter().any(|camera| camera.enabled) {
+        return;
+    }
+
+    // once the effect has been seen working, there's nothing to explain
+    if cameras.iter().any(ColorBlindnessCamera::is_effect_active) {
+        *done = true;
+        return;
+    }
+
+    *elapsed += time.delta_seconds();
+    if *elapsed >= IDENTITY_NUDGE_DELAY {
+        info!("Color blindness diagnostics: no `ColorBlindnessCamera` has changed the image yet. Pick a mode other than `ColorBlindnessMode::Normal` to see the simulation");
+        *done = true;
+    }
+}
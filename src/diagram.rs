@@ -0,0 +1,202 @@
+use bevy::{
+    prelude::*,
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+};
+
+use crate::ColorBlindnessMode;
+
+/// Size in pixels of the image returned by [`confusion_diagram`].
+pub const CONFUSION_DIAGRAM_SIZE: u32 = 256;
+
+/// Number of confusion lines drawn by [`confusion_diagram`].
+const CONFUSION_LINES: usize = 13;
+
+/// Region of the CIE 1931 xy chromaticity plane covered by the diagram.
+const CHROMATICITY_MAX: Vec2 = Vec2::new(0.8, 0.9);
+
+/// Primaries of the sRGB gamut in xy chromaticity coordinates.
+const SRGB_PRIMARIES: [Vec2; 3] = [
+    Vec2::new(0.64, 0.33),
+    Vec2::new(0.30, 0.60),
+    Vec2::new(0.15, 0.06),
+];
+
+/// Draws the confusion lines of `mode` over the sRGB gamut on a CIE 1931 xy chromaticity diagram.
+///
+/// All confusion lines of a dichromat meet at a single point, the copunctal point.
+/// Colors lying on the same line can't be told apart (other than by brightness), which helps
+/// explain *why* some color pairs are confused.
+///
+/// Copunctal points are taken from [Judd (1944)](https://doi.org/10.6028/jres.033.020) as used by
+/// [Brettel et al. (1997)](https://doi.org/10.1364/JOSAA.14.002647):
+/// protan `(0.7465, 0.2535)`, deutan `(1.4, -0.4)` and tritan `(0.1748, 0.0)`.
+/// Anomalous trichromacies use the lines of their dichromatic counterpart.
+/// Modes without confusion lines (like [`ColorBlindnessMode::Normal`] and monochromacies)
+/// only draw the gamut.
+///
+/// The returned image is [`CONFUSION_DIAGRAM_SIZE`] pixels wide and tall, in `Rgba8UnormSrgb`.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_color_blindness::*;
+/// fn show_diagram(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+///     let image = images.add(confusion_diagram(&ColorBlindnessMode::Deuteranopia));
+///     commands.spawn(ImageBundle {
+///         image: image.into(),
+///         ..default()
+///     });
+/// }
+/// ```
+pub fn confusion_diagram(mode: &ColorBlindnessMode) -> Image {
+    let lines = confusion_lines(mode);
+
+    let size = CONFUSION_DIAGRAM_SIZE;
+    let pixel_size = CHROMATICITY_MAX / size as f32;
+    let mut data = Vec::with_capacity((size * size * 4) as usize);
+    for y in 0..size {
+        for x in 0..size {
+            // flip y so the origin is at the bottom left, like in the usual diagram
+            let xy = Vec2::new(x as f32 + 0.5, (size - y) as f32 - 0.5) * pixel_size;
+
+            let mut color = if in_srgb_gamut(xy) {
+                chromaticity_to_color(xy)
+            } else {
+                Color::rgb(0.1, 0.1, 0.1)
+            };
+
+            if let Some((point, directions)) = &lines {
+                let offset = xy - *point;
+                // lines extend past the copunctal point too, so this is the distance to the whole line
+                let distance = directions
+                    .iter()
+                    .map(|direction| direction.perp_dot(offset).abs())
+                    .fold(f32::INFINITY, f32::min)
+                    / pixel_size.x;
+                if distance < 0.75 {
+                    color = Color::BLACK;
+                }
+            }
+
+            data.extend_from_slice(&color.as_rgba_u8());
+        }
+    }
+
+    Image::new(
+        Extent3d {
+            width: size,
+            height: size,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+    )
+}
+
+/// Copunctal point of `mode` and the unit directions of the confusion lines through it,
+/// spread evenly over the angle the sRGB gamut covers as seen from the copunctal point.
+fn confusion_lines(mode: &ColorBlindnessMode) -> Option<(Vec2, Vec<Vec2>)> {
+    let point = copunctal_point(mode)?;
+    let center = (SRGB_PRIMARIES[0] + SRGB_PRIMARIES[1] + SRGB_PRIMARIES[2]) / 3.0;
+    let direction = (center - point).normalize();
+    let (min, max) = SRGB_PRIMARIES
+        .iter()
+        .fold((0.0f32, 0.0f32), |(min, max), p| {
+            let angle = relative_angle(direction, *p - point);
+            (min.min(angle), max.max(angle))
+        });
+    let step = (max - min) / (CONFUSION_LINES - 1) as f32;
+    let directions = (0..CONFUSION_LINES)
+        .map(|i| Vec2::from_angle(min + i as f32 * step).rotate(direction))
+        .collect();
+    Some((point, directions))
+}
+
+/// Copunctal point of the dichromacy corresponding to `mode`, in xy chromaticity coordinates.
+fn copunctal_point(mode: &ColorBlindnessMode) -> Option<Vec2> {
+    match mode {
+        ColorBlindnessMode::Protanopia | ColorBlindnessMode::Protanomaly => {
+            Some(Vec2::new(0.7465, 0.2535))
+        }
+        ColorBlindnessMode::Deuteranopia | ColorBlindnessMode::Deuteranomaly => {
+            Some(Vec2::new(1.4, -0.4))
+        }
+        ColorBlindnessMode::Tritanopia | ColorBlindnessMode::Tritanomaly => {
+            Some(Vec2::new(0.1748, 0.0))
+        }
+        ColorBlindnessMode::Normal
+        | ColorBlindnessMode::Achromatopsia
+        | ColorBlindnessMode::Achromatomaly
+        | ColorBlindnessMode::Monochromacy { .. } => None,
+    }
+}
+
+/// Signed angle from `from` to `to`, in the range `-PI..=PI`.
+fn relative_angle(from: Vec2, to: Vec2) -> f32 {
+    from.perp_dot(to).atan2(from.dot(to))
+}
+
+fn in_srgb_gamut(xy: Vec2) -> bool {
+    let [r, g, b] = SRGB_PRIMARIES;
+    let d1 = (g - r).perp_dot(xy - r);
+    let d2 = (b - g).perp_dot(xy - g);
+    let d3 = (r - b).perp_dot(xy - b);
+    (d1 >= 0.0 && d2 >= 0.0 && d3 >= 0.0) || (d1 <= 0.0 && d2 <= 0.0 && d3 <= 0.0)
+}
+
+/// Brightest sRGB color with the given xy chromaticity.
+fn chromaticity_to_color(xy: Vec2) -> Color {
+    // xyY to XYZ, with Y = 1
+    let xyz = Vec3::new(xy.x / xy.y, 1.0, (1.0 - xy.x - xy.y) / xy.y);
+    let rgb = Vec3::new(
+        3.2406 * xyz.x - 1.5372 * xyz.y - 0.4986 * xyz.z,
+        -0.9689 * xyz.x + 1.8758 * xyz.y + 0.0415 * xyz.z,
+        0.0557 * xyz.x - 0.2040 * xyz.y + 1.0570 * xyz.z,
+    )
+    .max(Vec3::ZERO);
+    let rgb = rgb / rgb.max_element();
+    Color::rgb_linear(rgb.x, rgb.y, rgb.z)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diagram_is_a_square_srgb_image() {
+        let image = confusion_diagram(&ColorBlindnessMode::Normal);
+        assert_eq!(
+            image.texture_descriptor.size,
+            Extent3d {
+                width: CONFUSION_DIAGRAM_SIZE,
+                height: CONFUSION_DIAGRAM_SIZE,
+                depth_or_array_layers: 1,
+            }
+        );
+        assert_eq!(
+            image.texture_descriptor.format,
+            TextureFormat::Rgba8UnormSrgb
+        );
+    }
+
+    #[test]
+    fn lines_are_drawn_on_both_sides_of_the_copunctal_point() {
+        let mode = ColorBlindnessMode::Protanopia;
+        let image = confusion_diagram(&mode);
+        let (point, directions) = confusion_lines(&mode).unwrap();
+
+        let size = CONFUSION_DIAGRAM_SIZE;
+        let pixel_size = CHROMATICITY_MAX / size as f32;
+        let is_drawn = |xy: Vec2| {
+            let pixel = (xy / pixel_size).floor();
+            let (x, y) = (pixel.x as u32, size - 1 - pixel.y as u32);
+            let index = ((y * size + x) * 4) as usize;
+            image.data[index..index + 4] == Color::BLACK.as_rgba_u8()
+        };
+
+        // the middle line, towards the gamut and past the copunctal point
+        let middle = directions[CONFUSION_LINES / 2];
+        assert!(is_drawn(point + middle * 0.2));
+        assert!(is_drawn(point - middle * 0.05));
+    }
+}
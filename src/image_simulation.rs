@@ -0,0 +1,308 @@
+use std::fmt;
+
+use bevy::{prelude::*, render::render_resource::TextureFormat};
+
+use crate::{delta_e, ColorBlindnessMode};
+
+/// Error returned by [`simulate_image`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum SimulateImageError {
+    /// The image's texture format is not supported.
+    UnsupportedFormat(TextureFormat),
+}
+
+impl fmt::Display for SimulateImageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SimulateImageError::UnsupportedFormat(format) => {
+                write!(f, "unsupported texture format {format:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SimulateImageError {}
+
+/// Returns a copy of `image` as seen under `mode`, simulated on the CPU.
+///
+/// This is useful for offline tooling, like previewing how a sprite sheet looks
+/// without running the game, and for tests.
+///
+/// Supported formats are `Rgba8UnormSrgb`, `Rgba8Unorm`, `Bgra8UnormSrgb`, and `Bgra8Unorm`.
+/// Like on the GPU, sRGB formats are simulated on linear values, and non-sRGB formats
+/// on their stored values. Any other format returns [`SimulateImageError::UnsupportedFormat`].
+///
+/// ```rust
+/// # use bevy::{prelude::*, render::render_resource::{Extent3d, TextureDimension, TextureFormat}};
+/// # use bevy_color_blindness::*;
+/// let size = Extent3d {
+///     width: 1,
+///     height: 1,
+///     depth_or_array_layers: 1,
+/// };
+/// let red = Image::new(
+///     size,
+///     TextureDimension::D2,
+///     vec![255, 0, 0, 255],
+///     TextureFormat::Rgba8UnormSrgb,
+/// );
+///
+/// let simulated = simulate_image(&ColorBlindnessMode::Achromatopsia, &red).unwrap();
+/// // red turns into a shade of gray
+/// assert_eq!(simulated.data[0], simulated.data[1]);
+/// assert_eq!(simulated.data[1], simulated.data[2]);
+///
+/// // alpha is left untouched, like in the post-process
+/// let transparent = Image::new(
+///     size,
+///     TextureDimension::D2,
+///     vec![0, 255, 0, 128],
+///     TextureFormat::Rgba8UnormSrgb,
+/// );
+/// let simulated = simulate_image(&ColorBlindnessMode::Deuteranopia, &transparent).unwrap();
+/// assert_eq!(simulated.data[3], 128);
+///
+/// let single_channel = Image::new(size, TextureDimension::D2, vec![255], TextureFormat::R8Unorm);
+/// assert_eq!(
+///     simulate_image(&ColorBlindnessMode::Achromatopsia, &single_channel).unwrap_err(),
+///     SimulateImageError::UnsupportedFormat(TextureFormat::R8Unorm),
+/// );
+/// ```
+pub fn simulate_image(
+    mode: &ColorBlindnessMode,
+    image: &Image,
+) -> Result<Image, SimulateImageError> {
+    let (srgb, bgra) = pixel_layout(image)?;
+
+    let percentages = mode.percentages();
+    let mut simulated = image.clone();
+    for pixel in simulated.data.chunks_exact_mut(4) {
+        if bgra {
+            pixel.swap(0, 2);
+        }
+
+        let color = percentages.apply(read_pixel(pixel, srgb));
+        // rounded rather than truncated, so values which go through the conversions unchanged
+        // come back as the same byte
+        let output = if srgb {
+            color.as_rgba_f32()
+        } else {
+            color.as_linear_rgba_f32()
+        }
+        .map(|c| (c * 255.0).round().clamp(0.0, 255.0) as u8);
+        pixel.copy_from_slice(&output);
+
+        if bgra {
+            pixel.swap(0, 2);
+        }
+    }
+
+    Ok(simulated)
+}
+
+/// Difference between how an image looks under two modes, returned by [`compare_modes`].
+///
+/// Differences are [`delta_e`] values, where about 2.3 is just noticeable.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModeComparison {
+    /// Mean difference over the whole image
+    pub mean: f32,
+    /// Largest difference of any pixel
+    pub max: f32,
+    /// Number of regions the image was split into, horizontally and vertically
+    pub grid: UVec2,
+    /// Mean difference of each region, row by row, starting at the top left
+    pub regions: Vec<f32>,
+}
+
+impl ModeComparison {
+    /// Returns the mean difference of the region at `column` and `row` of the grid.
+    pub fn region(&self, column: u32, row: u32) -> f32 {
+        self.regions[(row * self.grid.x + column) as usize]
+    }
+}
+
+/// Simulates `image` under modes `a` and `b` on the CPU, and measures how different the results are,
+/// both overall and for each cell of a `grid` laid over the image.
+///
+/// Comparing a screenshot of a scene before and after a palette change (e.g. against
+/// [`ColorBlindnessMode::Normal`]) shows whether the change made things more or less similar
+/// to how they look with normal vision. Supports the same formats as [`simulate_image`].
+///
+/// ```rust
+/// # use bevy::{prelude::*, render::render_resource::{Extent3d, TextureDimension, TextureFormat}};
+/// # use bevy_color_blindness::*;
+/// // a red pixel next to a gray one
+/// let image = Image::new(
+///     Extent3d {
+///         width: 2,
+///         height: 1,
+///         depth_or_array_layers: 1,
+///     },
+///     TextureDimension::D2,
+///     vec![255, 0, 0, 255, 128, 128, 128, 255],
+///     TextureFormat::Rgba8UnormSrgb,
+/// );
+///
+/// let comparison = compare_modes(
+///     &image,
+///     &ColorBlindnessMode::Normal,
+///     &ColorBlindnessMode::Protanopia,
+///     UVec2::new(2, 1),
+/// )
+/// .unwrap();
+/// // red changes a lot, gray barely changes
+/// assert!(comparison.region(0, 0) > 10.0);
+/// assert!(comparison.region(1, 0) < 1.0);
+/// assert_eq!(comparison.max, comparison.region(0, 0));
+/// ```
+pub fn compare_modes(
+    image: &Image,
+    a: &ColorBlindnessMode,
+    b: &ColorBlindnessMode,
+    grid: UVec2,
+) -> Result<ModeComparison, SimulateImageError> {
+    compare_modes_downsampled(image, a, b, grid, 1)
+}
+
+/// Like [`compare_modes`], but compares a copy of `image` downsampled by `factor` in each dimension,
+/// which is faster for large images like full resolution screenshots.
+///
+/// Every `factor` by `factor` block of pixels is averaged into one, so the comparison does
+/// `factor²` times less work. A factor of 4 is usually a good trade-off for audits.
+/// Since colors are averaged before being compared, the results are less precise:
+/// `mean` and the region means stay close to the full resolution ones for smooth areas,
+/// but details smaller than a block, like thin lines and text, are blended with their surroundings,
+/// so `max` tends to be lower and small problem spots can be missed.
+/// A `factor` of `0` or `1` compares every pixel, like [`compare_modes`].
+///
+/// ```rust
+/// # use bevy::{prelude::*, render::render_resource::{Extent3d, TextureDimension, TextureFormat}};
+/// # use bevy_color_blindness::*;
+/// // left half red, right half gray
+/// let data = (0..8 * 8)
+///     .flat_map(|i| if i % 8 < 4 { [255, 0, 0, 255] } else { [128, 128, 128, 255] })
+///     .collect();
+/// let image = Image::new(
+///     Extent3d {
+///         width: 8,
+///         height: 8,
+///         depth_or_array_layers: 1,
+///     },
+///     TextureDimension::D2,
+///     data,
+///     TextureFormat::Rgba8UnormSrgb,
+/// );
+///
+/// let (a, b, grid) = (&ColorBlindnessMode::Normal, &ColorBlindnessMode::Protanopia, UVec2::new(2, 1));
+/// let full = compare_modes(&image, a, b, grid).unwrap();
+/// let downsampled = compare_modes_downsampled(&image, a, b, grid, 4).unwrap();
+/// // uniform areas give the same results
+/// assert!((full.region(0, 0) - downsampled.region(0, 0)).abs() < 0.01);
+/// assert!((full.mean - downsampled.mean).abs() < 0.01);
+/// ```
+pub fn compare_modes_downsampled(
+    image: &Image,
+    a: &ColorBlindnessMode,
+    b: &ColorBlindnessMode,
+    grid: UVec2,
+    factor: u32,
+) -> Result<ModeComparison, SimulateImageError> {
+    let (srgb, bgra) = pixel_layout(image)?;
+    let grid = grid.max(UVec2::ONE);
+    let factor = factor.max(1);
+    let size = UVec2::new(
+        image.texture_descriptor.size.width,
+        image.texture_descriptor.size.height,
+    );
+    // size of the downsampled copy, counting partial blocks at the edges
+    let blocks = (size + UVec2::splat(factor - 1)) / factor;
+
+    let a = a.percentages();
+    let b = b.percentages();
+    let mut total = 0.0;
+    let mut max: f32 = 0.0;
+    let mut region_totals = vec![0.0; (grid.x * grid.y) as usize];
+    let mut region_counts = vec![0u32; region_totals.len()];
+
+    for block_y in 0..blocks.y {
+        for block_x in 0..blocks.x {
+            let min = UVec2::new(block_x, block_y) * factor;
+            let max_corner = (min + UVec2::splat(factor)).min(size);
+
+            let color = if factor == 1 {
+                pixel_at(image, min, size.x, srgb, bgra)
+            } else {
+                // averaged in linear space, like filtering on the GPU
+                let mut sum = Vec4::ZERO;
+                for y in min.y..max_corner.y {
+                    for x in min.x..max_corner.x {
+                        sum += Vec4::from(
+                            pixel_at(image, UVec2::new(x, y), size.x, srgb, bgra)
+                                .as_linear_rgba_f32(),
+                        );
+                    }
+                }
+                let count = (max_corner - min).x * (max_corner - min).y;
+                let [r, g, b, alpha] = (sum / count as f32).to_array();
+                Color::rgba_linear(r, g, b, alpha)
+            };
+            let difference = delta_e(a.apply(color), b.apply(color));
+
+            let cell = min * grid / size;
+            let region = (cell.y * grid.x + cell.x) as usize;
+            region_totals[region] += difference;
+            region_counts[region] += 1;
+            total += difference;
+            max = max.max(difference);
+        }
+    }
+
+    let compared = (blocks.x * blocks.y).max(1);
+    Ok(ModeComparison {
+        mean: total / compared as f32,
+        max,
+        grid,
+        regions: region_totals
+            .into_iter()
+            .zip(region_counts)
+            .map(|(total, count)| total / count.max(1) as f32)
+            .collect(),
+    })
+}
+
+/// Reads the pixel at `position` of `image`, which is `width` pixels wide.
+fn pixel_at(image: &Image, position: UVec2, width: u32, srgb: bool, bgra: bool) -> Color {
+    let start = ((position.y * width + position.x) * 4) as usize;
+    let mut pixel = [
+        image.data[start],
+        image.data[start + 1],
+        image.data[start + 2],
+        image.data[start + 3],
+    ];
+    if bgra {
+        pixel.swap(0, 2);
+    }
+    read_pixel(&pixel, srgb)
+}
+
+/// Returns whether `image`'s format is sRGB, and whether it's BGRA, for the supported formats.
+fn pixel_layout(image: &Image) -> Result<(bool, bool), SimulateImageError> {
+    match image.texture_descriptor.format {
+        TextureFormat::Rgba8UnormSrgb => Ok((true, false)),
+        TextureFormat::Rgba8Unorm => Ok((false, false)),
+        TextureFormat::Bgra8UnormSrgb => Ok((true, true)),
+        TextureFormat::Bgra8Unorm => Ok((false, true)),
+        format => Err(SimulateImageError::UnsupportedFormat(format)),
+    }
+}
+
+/// Reads an RGBA pixel, as stored in an sRGB format if `srgb`, or as linear values otherwise.
+fn read_pixel(pixel: &[u8], srgb: bool) -> Color {
+    if srgb {
+        Color::rgba_u8(pixel[0], pixel[1], pixel[2], pixel[3])
+    } else {
+        let [r, g, b, a] = [pixel[0], pixel[1], pixel[2], pixel[3]].map(|c| c as f32 / 255.0);
+        Color::rgba_linear(r, g, b, a)
+    }
+}
@@ -9,7 +9,7 @@
 //!
 //! Based on [Alan Zucconi's post](https://www.alanzucconi.com/2015/12/16/color-blindness/).
 //! Supports: Normal, Protanopia, Protanomaly, Deuteranopia, Deuteranomaly,
-//! Tritanopia, Tritanomaly, Achromatopsia, and Achromatomaly.
+//! Tritanopia, Tritanomaly, Achromatopsia, Achromatomaly, and Monochromacy.
 //!
 //! # Using
 //!
@@ -31,7 +31,7 @@
 //!         .add_plugins(DefaultPlugins)
 //!         // add the plugin
 //!         .add_plugin(ColorBlindnessPlugin)
-//!         .add_startup_system(setup)
+//!         .add_systems(Startup, setup)
 //!         .run();
 //! }
 //!
@@ -40,83 +40,95 @@
 //!
 //!     // create the camera
 //!     commands
-//!         .spawn_bundle(Camera3dBundle {
+//!         .spawn(Camera3dBundle {
 //!             transform: Transform::from_xyz(-2.0, 2.5, 5.0).looking_at(Vec3::ZERO, Vec3::Y),
 //!             ..Default::default()
 //!         })
 //!         .insert(ColorBlindnessCamera {
 //!             mode: ColorBlindnessMode::Deuteranopia,
 //!             enabled: true,
+//!             ..Default::default()
 //!         });
 //! }
 //! ```
 //!
+//! # Running headlessly
+//!
+//! The plugin works without a renderer, e.g. in tests or on servers sharing plugin sets
+//! with clients: cameras still get their [`ColorBlindnessPostProcess`] and
+//! [`ColorBlindnessActive`] is kept up to date, there's just nothing to draw.
+//! The examples are built by `cargo test`, so they can't drift from the API,
+//! and `tests/headless.rs` checks the main world side of the plugin this way.
+//!
 //! # Important note
 //!
 //! This plugin only simulates how color blind players will see your game.
 //! It does not correct for color blindness to make your game more accessible.
 //! This plugin should only be used during development, and removed on final builds.
 
+pub mod commands;
+pub mod debug;
+#[cfg(feature = "diagnostics")]
+pub mod diagnostics;
+pub mod diagram;
+pub mod image_simulation;
+pub mod material;
+pub mod palette;
 pub mod plugin;
+pub mod preset;
+pub mod preview;
+pub mod telemetry;
+pub use commands::*;
+pub use debug::*;
+#[cfg(feature = "diagnostics")]
+pub use diagnostics::*;
+pub use diagram::*;
+pub use image_simulation::*;
+pub use material::*;
+pub use palette::*;
 pub use plugin::*;
+pub use preset::*;
+pub use preview::*;
+pub use telemetry::*;
 
 use bevy::{prelude::*, render::render_resource::ShaderType};
 
-/// Plugin to simulate and preview different types of
-/// color blindness.
-///
-/// This lets you ensure that your game is accessible to all players by testing how it
-/// will be seen under different conditions. While this is important,
-/// please also consider not relying on color alone to convey important information to your players.
-/// A common option is to add identifying symbols, like in the game
-/// [Hue](https://gameaccessibilityguidelines.com/hue-colorblind-mode/).
-///
-/// Based on [Alan Zucconi's post](https://www.alanzucconi.com/2015/12/16/color-blindness/).
-/// Supports: Normal, Protanopia, Protanomaly, Deuteranopia, Deuteranomaly,
-/// Tritanopia, Tritanomaly, Achromatopsia, and Achromatomaly.
-///
-/// First, add the [`ColorBlindnessPlugin`] to your app, and add [`ColorBlindnessCamera`] to
-/// your main camera.
-///
-/// ```rust,no_run
-/// use bevy::prelude::*;
-/// use bevy_color_blindness::*;
-///
-/// fn main() {
-///     App::new()
-///         .add_plugins(DefaultPlugins)
-///         // add the plugin
-///         .add_plugin(ColorBlindnessPlugin)
-///         .add_startup_system(setup)
-///         .run();
-/// }
-///
-/// fn setup(mut commands: Commands) {
-///     // set up your scene...
-///
-///     // create the camera
-///     commands
-///         .spawn_bundle(Camera3dBundle {
-///             transform: Transform::from_xyz(-2.0, 2.5, 5.0).looking_at(Vec3::ZERO, Vec3::Y),
-///             ..Default::default()
-///         })
-///         .insert(ColorBlindnessCamera {
-///             mode: ColorBlindnessMode::Deuteranopia,
-///             enabled: true,
-///         });
-/// }
-/// ```
-///
-/// # Important note
-///
-/// This plugin only simulates how color blind players will see your game.
-/// It does not correct for color blindness to make your game more accessible.
-/// This plugin should only be used during development, and removed on final builds.
-
 /// The different modes of color blindness simulation supported.
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ColorBlindnessMode {
     /// Normal full color vision
+    ///
+    /// This never alters the image: cameras simulating normal vision skip the post-process entirely,
+    /// and on the CPU its percentages are an exact identity, so every 8 bit value comes back unchanged:
+    ///
+    /// ```rust
+    /// # use bevy::{prelude::*, render::render_resource::{Extent3d, TextureDimension, TextureFormat}};
+    /// # use bevy_color_blindness::*;
+    /// let camera = ColorBlindnessCamera {
+    ///     mode: ColorBlindnessMode::Normal,
+    ///     enabled: true,
+    ///     ..default()
+    /// };
+    /// assert!(!camera.is_effect_active());
+    ///
+    /// // a gradient going through every value of every channel
+    /// let data: Vec<u8> = (0..=255u8).flat_map(|v| [v, 255 - v, v / 2 + 64, v]).collect();
+    /// for format in [TextureFormat::Rgba8UnormSrgb, TextureFormat::Rgba8Unorm] {
+    ///     let gradient = Image::new(
+    ///         Extent3d {
+    ///             width: 256,
+    ///             height: 1,
+    ///             depth_or_array_layers: 1,
+    ///         },
+    ///         TextureDimension::D2,
+    ///         data.clone(),
+    ///         format,
+    ///     );
+    ///     let simulated = simulate_image(&ColorBlindnessMode::Normal, &gradient).unwrap();
+    ///     assert_eq!(simulated.data, gradient.data);
+    /// }
+    /// ```
     #[default]
     Normal,
     // Descriptions of the different types of color blindness are sourced from:
@@ -130,6 +142,14 @@ pub enum ColorBlindnessMode {
     /// Condition where green looks more red.
     Deuteranomaly,
     /// Inability to differentiate between blue and green, purple and red, and yellow and pink.
+    ///
+    /// Unlike the other modes, this one uses the tritanopia matrix from
+    /// [Machado, Oliveira and Fernandes (2009)](https://www.inf.ufrgs.br/~oliveira/pubs_files/CVD_Simulation/CVD_Simulation.html)
+    /// at full severity, since the ColorJack one doesn't follow the loss of the S (blue) cones. Colors which only differ in
+    /// how much they excite the S (blue) cones end up about five times closer, while red and green
+    /// are still told apart. A single matrix can't reproduce the two half-plane model of
+    /// [Brettel, Viénot and Mollon (1997)](https://doi.org/10.1364/JOSAA.14.002647), so this is an
+    /// approximation of the tritan neutral point rather than an exact projection onto it.
     Tritanopia,
     /// Difficulty differentiating between blue and green, and between yellow and red
     Tritanomaly,
@@ -142,6 +162,32 @@ pub enum ColorBlindnessMode {
     /// topic seems to corroborate this.
     /// It has been left in for completeness sake, but please be aware of this fact.
     Achromatomaly,
+    /// Grayscale vision which keeps some blue-yellow discrimination, as in incomplete achromatopsia
+    /// or blue cone monochromacy.
+    ///
+    /// `residual` is how much of the blue-yellow signal is left, from `0.0`,
+    /// which is the same as [`ColorBlindnessMode::Achromatopsia`], to `1.0`.
+    /// Small values like `0.1` are the most realistic.
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_color_blindness::*;
+    /// assert_eq!(
+    ///     ColorBlindnessMode::Monochromacy { residual: 0.0 }.percentages(),
+    ///     ColorBlindnessMode::Achromatopsia.percentages(),
+    /// );
+    ///
+    /// // grays stay gray, but blue is slightly bluer than it would be in grayscale
+    /// let percentages = ColorBlindnessMode::Monochromacy { residual: 0.1 }.percentages();
+    /// let white = percentages.apply(Color::WHITE).as_rgba_f32();
+    /// assert!(white.iter().all(|channel| (channel - 1.0).abs() < 1e-5));
+    /// let [r, g, b, _] = percentages.apply(Color::BLUE).as_rgba_f32();
+    /// assert!(b > r && r == g);
+    /// ```
+    Monochromacy {
+        /// How much of the blue-yellow signal is left, from `0.0` to `1.0`
+        residual: f32,
+    },
 }
 
 /// Indicates how to mix the RGB channels to obtain output colors.
@@ -161,7 +207,7 @@ pub enum ColorBlindnessMode {
 /// }
 /// # }
 /// ```
-#[derive(ShaderType, Clone, Default, Copy, Debug)]
+#[derive(ShaderType, Clone, Copy, Debug, PartialEq)]
 pub struct ColorBlindnessPercentages {
     /// Percentages of red, green, and blue to mix on the red channel.
     pub red: Vec3,
@@ -171,11 +217,125 @@ pub struct ColorBlindnessPercentages {
     pub blue: Vec3,
 }
 
+impl Default for ColorBlindnessPercentages {
+    /// Returns the percentages for normal vision, which leave colors unchanged.
+    fn default() -> Self {
+        ColorBlindnessMode::Normal.percentages()
+    }
+}
+
+impl std::fmt::Display for ColorBlindnessPercentages {
+    /// Formats the percentages as a labeled grid, with a row for each output channel
+    /// and a column for each input channel.
+    ///
+    /// Uses three decimals, unless a precision is specified.
+    ///
+    /// ```rust
+    /// # use bevy_color_blindness::*;
+    /// let percentages = ColorBlindnessMode::Protanopia.percentages();
+    /// assert_eq!(
+    ///     format!("{percentages:.2}"),
+    ///     "      R     G     B\n\
+    ///      R  0.57  0.43  0.00\n\
+    ///      G  0.56  0.44  0.00\n\
+    ///      B  0.00  0.24  0.76"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let precision = f.precision().unwrap_or(3);
+        // wide enough for a sign, a leading digit, and the decimals, plus a space between columns
+        let width = precision + 4;
+
+        write!(f, " ")?;
+        for label in ["R", "G", "B"] {
+            write!(f, "{label:>width$}")?;
+        }
+        for (label, row) in [("R", self.red), ("G", self.green), ("B", self.blue)] {
+            write!(f, "\n{label}")?;
+            for value in row.to_array() {
+                write!(f, "{value:>width$.precision$}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
 impl ColorBlindnessPercentages {
     /// Creates a new `ColorBlindnessPercentages`
     fn new(red: Vec3, green: Vec3, blue: Vec3) -> Self {
         Self { red, green, blue }
     }
+
+    /// Linearly interpolates between `self` and `other`, returning `self` at `t = 0.0`
+    /// and exactly `other` at `t = 1.0`.
+    ///
+    /// Bevy doesn't have a common interpolation trait yet, so this is an inherent method,
+    /// which is easy to wrap in whatever tweening crates expect (e.g. a `Lens` in `bevy_tweening`).
+    ///
+    /// For smooth transitions between normal vision and a mode, animating
+    /// [`ColorBlindnessCamera::severity`] is usually simpler, since it's just an `f32`:
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_color_blindness::*;
+    /// // fades the simulation in over a second
+    /// fn fade_in(time: Res<Time>, mut cameras: Query<&mut ColorBlindnessCamera>) {
+    ///     for mut camera in &mut cameras {
+    ///         if camera.severity < 1.0 {
+    ///             camera.severity = (camera.severity + time.delta_seconds()).min(1.0);
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let normal = ColorBlindnessMode::Normal.percentages();
+    /// let protanopia = ColorBlindnessMode::Protanopia.percentages();
+    /// assert_eq!(normal.lerp(&protanopia, 0.0), normal);
+    /// assert_eq!(normal.lerp(&protanopia, 1.0), protanopia);
+    /// ```
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        // written out instead of using `Vec3::lerp`, so `t = 1.0` gives exactly `other`
+        let blend = |from: Vec3, to: Vec3| from * (1.0 - t) + to * t;
+        Self::new(
+            blend(self.red, other.red),
+            blend(self.green, other.green),
+            blend(self.blue, other.blue),
+        )
+    }
+
+    /// Applies the percentages to `color` on the CPU, matching what the post-process does on the GPU.
+    ///
+    /// The post-process works on linear values, so `color` is converted to linear RGB first,
+    /// whichever representation it uses. The result is always returned as [`Color::Rgba`],
+    /// with alpha left unchanged.
+    ///
+    /// Channels are not clamped, so custom percentages can produce values outside of `0.0..=1.0`.
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_color_blindness::*;
+    /// let percentages = ColorBlindnessMode::Deuteranopia.percentages();
+    ///
+    /// // the same color gives the same result, no matter the representation
+    /// let from_hsl = percentages.apply(Color::hsl(120.0, 1.0, 0.5));
+    /// let from_rgba = percentages.apply(Color::rgb(0.0, 1.0, 0.0));
+    /// let from_linear = percentages.apply(Color::rgb_linear(0.0, 1.0, 0.0));
+    /// assert!(matches!(from_hsl, Color::Rgba { .. }));
+    /// for other in [from_rgba, from_linear] {
+    ///     let difference = Vec4::from(from_hsl.as_rgba_f32()) - Vec4::from(other.as_rgba_f32());
+    ///     assert!(difference.abs().max_element() < 1e-5);
+    /// }
+    /// ```
+    pub fn apply(&self, color: Color) -> Color {
+        let [r, g, b, a] = color.as_linear_rgba_f32();
+        let rgb = Vec3::new(r, g, b);
+        Color::rgba_linear(
+            self.red.dot(rgb),
+            self.green.dot(rgb),
+            self.blue.dot(rgb),
+            a,
+        )
+        .as_rgba()
+    }
 }
 
 impl ColorBlindnessMode {
@@ -183,6 +343,22 @@ impl ColorBlindnessMode {
     ///
     /// [Source](https://web.archive.org/web/20081014161121/http://www.colorjack.com/labs/colormatrix/)
     pub fn percentages(&self) -> ColorBlindnessPercentages {
+        self.percentages_with_luminance(LuminanceStandard::default())
+    }
+
+    /// Returns the percentages of colors to mix corresponding to each type of color blindness,
+    /// using the weights of `luminance` for [`ColorBlindnessMode::Achromatopsia`].
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_color_blindness::*;
+    /// let rec709 = ColorBlindnessMode::Achromatopsia.percentages_with_luminance(LuminanceStandard::Rec709);
+    /// assert_eq!(rec709.red, Vec3::new(0.2126, 0.7152, 0.0722));
+    /// ```
+    pub fn percentages_with_luminance(
+        &self,
+        luminance: LuminanceStandard,
+    ) -> ColorBlindnessPercentages {
         // table from https://www.alanzucconi.com/2015/12/16/color-blindness/
         // https://web.archive.org/web/20081014161121/http://www.colorjack.com/labs/colormatrix/
 
@@ -208,26 +384,38 @@ impl ColorBlindnessMode {
                 [0.25833, 0.74167, 0.0].into(),
                 [0.0, 0.14167, 0.85833].into(),
             ),
+            // tritanopia uses the severity 1.0 matrix from Machado et al. (2009), which is meant for linear sRGB
             ColorBlindnessMode::Tritanopia => ColorBlindnessPercentages::new(
-                [0.95, 0.5, 0.0].into(),
-                [0.0, 0.43333, 0.56667].into(),
-                [0.0, 0.475, 0.525].into(),
+                [1.255528, -0.076749, -0.178779].into(),
+                [-0.078411, 0.930809, 0.147602].into(),
+                [0.004733, 0.691367, 0.303900].into(),
             ),
+            // ColorJack lists the red row as 96.667% red and 3.333% green
             ColorBlindnessMode::Tritanomaly => ColorBlindnessPercentages::new(
-                [0.96667, 0.3333, 0.0].into(),
+                [0.96667, 0.03333, 0.0].into(),
                 [0.0, 0.73333, 0.26667].into(),
                 [0.0, 0.18333, 0.81667].into(),
             ),
-            ColorBlindnessMode::Achromatopsia => ColorBlindnessPercentages::new(
-                [0.299, 0.587, 0.114].into(),
-                [0.299, 0.587, 0.114].into(),
-                [0.299, 0.587, 0.114].into(),
-            ),
+            ColorBlindnessMode::Achromatopsia => {
+                let weights = luminance.coefficients();
+                ColorBlindnessPercentages::new(weights, weights, weights)
+            }
             ColorBlindnessMode::Achromatomaly => ColorBlindnessPercentages::new(
                 [0.618, 0.32, 0.62].into(),
                 [0.163, 0.775, 0.62].into(),
                 [0.163, 0.320, 0.516].into(),
             ),
+            ColorBlindnessMode::Monochromacy { residual } => {
+                // grayscale, plus some of the blue-yellow opponent signal (blue minus yellow),
+                // added back along the same axis. rows still sum to 1, so grays are unchanged
+                let weights = luminance.coefficients();
+                let blue_yellow = Vec3::new(-0.5, -0.5, 1.0) * residual.clamp(0.0, 1.0);
+                ColorBlindnessPercentages::new(
+                    weights - 0.5 * blue_yellow,
+                    weights - 0.5 * blue_yellow,
+                    weights + blue_yellow,
+                )
+            }
         }
     }
 
@@ -260,16 +448,364 @@ impl ColorBlindnessMode {
             ColorBlindnessMode::Tritanopia => ColorBlindnessMode::Tritanomaly,
             ColorBlindnessMode::Tritanomaly => ColorBlindnessMode::Achromatopsia,
             ColorBlindnessMode::Achromatopsia => ColorBlindnessMode::Achromatomaly,
-            ColorBlindnessMode::Achromatomaly => ColorBlindnessMode::Normal,
+            ColorBlindnessMode::Achromatomaly => ColorBlindnessMode::Monochromacy { residual: 0.1 },
+            ColorBlindnessMode::Monochromacy { .. } => ColorBlindnessMode::Normal,
         };
     }
+
+    /// Changes `self` to the next `ColorBlindnessMode`, skipping modes which aren't clinically recognized.
+    ///
+    /// See [`ColorBlindnessMode::is_clinically_recognized`].
+    ///
+    /// ```rust
+    /// # use bevy_color_blindness::*;
+    /// let mut mode = ColorBlindnessMode::Achromatopsia;
+    /// mode.cycle_real();
+    /// assert!(matches!(mode, ColorBlindnessMode::Monochromacy { .. }));
+    /// ```
+    pub fn cycle_real(&mut self) {
+        self.cycle();
+        while !self.is_clinically_recognized() {
+            self.cycle();
+        }
+    }
+
+    /// Returns whether this mode simulates a recognized condition, or normal vision.
+    ///
+    /// Only [`ColorBlindnessMode::Achromatomaly`] isn't, see its documentation for details.
+    ///
+    /// ```rust
+    /// # use bevy_color_blindness::*;
+    /// assert!(ColorBlindnessMode::Normal.is_clinically_recognized());
+    /// assert!(ColorBlindnessMode::Protanopia.is_clinically_recognized());
+    /// assert!(ColorBlindnessMode::Protanomaly.is_clinically_recognized());
+    /// assert!(ColorBlindnessMode::Deuteranopia.is_clinically_recognized());
+    /// assert!(ColorBlindnessMode::Deuteranomaly.is_clinically_recognized());
+    /// assert!(ColorBlindnessMode::Tritanopia.is_clinically_recognized());
+    /// assert!(ColorBlindnessMode::Tritanomaly.is_clinically_recognized());
+    /// assert!(ColorBlindnessMode::Achromatopsia.is_clinically_recognized());
+    /// assert!(!ColorBlindnessMode::Achromatomaly.is_clinically_recognized());
+    /// assert!(ColorBlindnessMode::Monochromacy { residual: 0.1 }.is_clinically_recognized());
+    /// ```
+    pub fn is_clinically_recognized(&self) -> bool {
+        !matches!(self, ColorBlindnessMode::Achromatomaly)
+    }
+
+    /// Returns the approximate fraction of the population with each condition, from `0.0` to `1.0`.
+    ///
+    /// Useful for deciding which modes to test first, or for annotating options in a menu.
+    /// These are rough figures, and vary a lot between populations:
+    /// - Red-green conditions are X-linked, so the figures are for males of
+    ///   Northern European descent, from [Wikipedia](https://en.wikipedia.org/wiki/Color_blindness#Epidemiology)
+    ///   (citing [Sharpe et al., 1999](https://www.researchgate.net/publication/238700157_Opsin_genes_cone_photopigments_color_vision_and_color_blindness)).
+    ///   Rates for females are much lower, around 0.5% for all of them combined.
+    /// - Tritan conditions affect both sexes equally, at roughly 1 in 10,000
+    ///   ([Colour Blind Awareness](https://www.colourblindawareness.org/colour-blindness/types-of-colour-blindness/)).
+    /// - Achromatopsia affects roughly 1 in 30,000 people
+    ///   ([NIH](https://medlineplus.gov/genetics/condition/achromatopsia/)).
+    /// - Achromatomaly is often quoted at 1 in 100,000, but see the caveat on [`ColorBlindnessMode::Achromatomaly`].
+    /// - Monochromacy uses the figure for blue cone monochromacy, also roughly 1 in 100,000
+    ///   ([NIH](https://medlineplus.gov/genetics/condition/blue-cone-monochromacy/)).
+    ///
+    /// ```rust
+    /// # use bevy_color_blindness::*;
+    /// // deuteranomaly is the most common condition
+    /// assert!(ColorBlindnessMode::Deuteranomaly.prevalence() > ColorBlindnessMode::Protanopia.prevalence());
+    /// ```
+    pub fn prevalence(&self) -> f32 {
+        match self {
+            ColorBlindnessMode::Normal => 0.92,
+            ColorBlindnessMode::Protanopia => 0.0101,
+            ColorBlindnessMode::Protanomaly => 0.0108,
+            ColorBlindnessMode::Deuteranopia => 0.0127,
+            ColorBlindnessMode::Deuteranomaly => 0.0463,
+            ColorBlindnessMode::Tritanopia => 0.0001,
+            ColorBlindnessMode::Tritanomaly => 0.0001,
+            ColorBlindnessMode::Achromatopsia => 0.00003,
+            ColorBlindnessMode::Achromatomaly => 0.00001,
+            ColorBlindnessMode::Monochromacy { .. } => 0.00001,
+        }
+    }
+
+    /// Returns a short description of the condition, for showing to players, e.g. as a tooltip in a settings menu.
+    ///
+    /// These are the same as the variants' documentation, including the caveat
+    /// about [`ColorBlindnessMode::Achromatomaly`] not being found in nature.
+    ///
+    /// ```rust
+    /// # use bevy_color_blindness::*;
+    /// for mode in ColorBlindnessMode::all() {
+    ///     assert!(!mode.description().is_empty());
+    /// }
+    /// ```
+    pub fn description(&self) -> &'static str {
+        match self {
+            ColorBlindnessMode::Normal => "Normal full color vision.",
+            ColorBlindnessMode::Protanopia => "Inability to differentiate between green and red.",
+            ColorBlindnessMode::Protanomaly => "Condition where red looks more green.",
+            ColorBlindnessMode::Deuteranopia => "Inability to differentiate between green and red.",
+            ColorBlindnessMode::Deuteranomaly => "Condition where green looks more red.",
+            ColorBlindnessMode::Tritanopia => {
+                "Inability to differentiate between blue and green, purple and red, and yellow and pink."
+            }
+            ColorBlindnessMode::Tritanomaly => {
+                "Difficulty differentiating between blue and green, and between yellow and red."
+            }
+            ColorBlindnessMode::Achromatopsia => "Absence of color discrimination.",
+            ColorBlindnessMode::Achromatomaly => {
+                "All color cones have some form of deficiency. \
+                This is likely an extrapolation of other types of color blindness, \
+                and not actually found in nature."
+            }
+            ColorBlindnessMode::Monochromacy { .. } => {
+                "Grayscale vision which keeps some blue-yellow discrimination, \
+                as in incomplete achromatopsia or blue cone monochromacy."
+            }
+        }
+    }
+
+    /// Returns every mode, in the same order as [`ColorBlindnessMode::cycle`] and
+    /// [`ColorBlindnessMode::to_index`].
+    ///
+    /// `Monochromacy` uses a residual of `0.1`.
+    ///
+    /// ```rust
+    /// # use bevy_color_blindness::*;
+    /// for (i, mode) in ColorBlindnessMode::all().into_iter().enumerate() {
+    ///     assert_eq!(mode.to_index() as usize, i);
+    ///     assert_eq!(ColorBlindnessMode::from_index(i as u8), Some(mode));
+    /// }
+    /// ```
+    pub fn all() -> [ColorBlindnessMode; 10] {
+        [
+            ColorBlindnessMode::Normal,
+            ColorBlindnessMode::Protanopia,
+            ColorBlindnessMode::Protanomaly,
+            ColorBlindnessMode::Deuteranopia,
+            ColorBlindnessMode::Deuteranomaly,
+            ColorBlindnessMode::Tritanopia,
+            ColorBlindnessMode::Tritanomaly,
+            ColorBlindnessMode::Achromatopsia,
+            ColorBlindnessMode::Achromatomaly,
+            ColorBlindnessMode::Monochromacy { residual: 0.1 },
+        ]
+    }
+
+    /// Returns a numeric index for this mode, for compact encodings like save files,
+    /// network messages, or sliders.
+    ///
+    /// Indices are stable across minor versions: new modes are only ever added at the end.
+    /// Only the variant is encoded, so the residual of `Monochromacy` is lost.
+    ///
+    /// ```rust
+    /// # use bevy_color_blindness::*;
+    /// assert_eq!(ColorBlindnessMode::Normal.to_index(), 0);
+    /// assert_eq!(ColorBlindnessMode::Deuteranomaly.to_index(), 4);
+    /// assert_eq!(ColorBlindnessMode::Monochromacy { residual: 0.3 }.to_index(), 9);
+    /// ```
+    pub fn to_index(&self) -> u8 {
+        match self {
+            ColorBlindnessMode::Normal => 0,
+            ColorBlindnessMode::Protanopia => 1,
+            ColorBlindnessMode::Protanomaly => 2,
+            ColorBlindnessMode::Deuteranopia => 3,
+            ColorBlindnessMode::Deuteranomaly => 4,
+            ColorBlindnessMode::Tritanopia => 5,
+            ColorBlindnessMode::Tritanomaly => 6,
+            ColorBlindnessMode::Achromatopsia => 7,
+            ColorBlindnessMode::Achromatomaly => 8,
+            ColorBlindnessMode::Monochromacy { .. } => 9,
+        }
+    }
+
+    /// Returns the mode with the given index, see [`ColorBlindnessMode::to_index`].
+    ///
+    /// `Monochromacy` uses a residual of `0.1`. Returns `None` for indices past the last mode.
+    ///
+    /// ```rust
+    /// # use bevy_color_blindness::*;
+    /// assert_eq!(ColorBlindnessMode::from_index(5), Some(ColorBlindnessMode::Tritanopia));
+    /// assert_eq!(ColorBlindnessMode::from_index(10), None);
+    /// ```
+    pub fn from_index(index: u8) -> Option<Self> {
+        Self::all().into_iter().nth(index as usize)
+    }
+}
+
+/// Error returned when parsing a [`ColorBlindnessMode`] from a string.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseColorBlindnessModeError(String);
+
+impl std::fmt::Display for ParseColorBlindnessModeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown color blindness mode `{}`", self.0)
+    }
+}
+
+impl std::error::Error for ParseColorBlindnessModeError {}
+
+impl std::str::FromStr for ColorBlindnessMode {
+    type Err = ParseColorBlindnessModeError;
+
+    /// Parses the name of a mode, ignoring case.
+    ///
+    /// `Monochromacy` takes its residual after a colon, and uses `0.1` if it's left out.
+    ///
+    /// ```rust
+    /// # use bevy_color_blindness::*;
+    /// assert_eq!("deuteranopia".parse(), Ok(ColorBlindnessMode::Deuteranopia));
+    /// assert_eq!("Achromatopsia".parse(), Ok(ColorBlindnessMode::Achromatopsia));
+    /// assert_eq!(
+    ///     "monochromacy:0.2".parse(),
+    ///     Ok(ColorBlindnessMode::Monochromacy { residual: 0.2 })
+    /// );
+    /// assert!("purple".parse::<ColorBlindnessMode>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseColorBlindnessModeError(s.to_string());
+        let (name, parameter) = match s.split_once(':') {
+            Some((name, parameter)) => (name, Some(parameter)),
+            None => (s, None),
+        };
+
+        let mode = match name.to_lowercase().as_str() {
+            "monochromacy" => {
+                let residual = match parameter {
+                    Some(residual) => residual.trim().parse().map_err(|_| error())?,
+                    None => 0.1,
+                };
+                return Ok(ColorBlindnessMode::Monochromacy { residual });
+            }
+            "normal" => ColorBlindnessMode::Normal,
+            "protanopia" => ColorBlindnessMode::Protanopia,
+            "protanomaly" => ColorBlindnessMode::Protanomaly,
+            "deuteranopia" => ColorBlindnessMode::Deuteranopia,
+            "deuteranomaly" => ColorBlindnessMode::Deuteranomaly,
+            "tritanopia" => ColorBlindnessMode::Tritanopia,
+            "tritanomaly" => ColorBlindnessMode::Tritanomaly,
+            "achromatopsia" => ColorBlindnessMode::Achromatopsia,
+            "achromatomaly" => ColorBlindnessMode::Achromatomaly,
+            _ => return Err(error()),
+        };
+
+        // only monochromacy takes a parameter
+        match parameter {
+            Some(_) => Err(error()),
+            None => Ok(mode),
+        }
+    }
+}
+
+/// Set of luminance coefficients used to turn colors into grayscale in monochrome modes.
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub enum LuminanceStandard {
+    /// [ITU-R BT.601](https://www.itu.int/rec/R-REC-BT.601) weights: `0.299, 0.587, 0.114`.
+    ///
+    /// These are the weights from the original table, so this is the default.
+    #[default]
+    Rec601,
+    /// [ITU-R BT.709](https://www.itu.int/rec/R-REC-BT.709) weights: `0.2126, 0.7152, 0.0722`.
+    ///
+    /// sRGB shares its primaries with Rec.709, so this is arguably the most accurate choice for most content.
+    Rec709,
+    /// [ITU-R BT.2020](https://www.itu.int/rec/R-REC-BT.2020) weights: `0.2627, 0.6780, 0.0593`.
+    ///
+    /// Meant for wide-gamut content.
+    Rec2020,
+}
+
+impl LuminanceStandard {
+    /// Returns the weights of the red, green, and blue channels.
+    pub fn coefficients(&self) -> Vec3 {
+        match self {
+            LuminanceStandard::Rec601 => Vec3::new(0.299, 0.587, 0.114),
+            LuminanceStandard::Rec709 => Vec3::new(0.2126, 0.7152, 0.0722),
+            LuminanceStandard::Rec2020 => Vec3::new(0.2627, 0.6780, 0.0593),
+        }
+    }
+}
+
+/// What the post-process outputs when color blindness simulation is enabled.
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub enum Visualize {
+    /// The simulated image, as seen by a color blind person.
+    #[default]
+    Simulation,
+    /// The color information that is lost, as the absolute difference between the original
+    /// and the simulated image.
+    ///
+    /// Bright areas carry meaning a color blind player can't perceive, and are good candidates
+    /// for redundant cues like icons or patterns.
+    Residual,
+    /// The simulated image, dimmed, with boundaries that are visible in the original image
+    /// but vanish in the simulated one highlighted in magenta.
+    ///
+    /// Edges are found with a Sobel filter on both images. The highlighted areas are where
+    /// outlines or textures could be added so color isn't the only cue.
+    MissingEdges,
+    /// The original image, with the color information that is lost exaggerated.
+    ///
+    /// The opposite of the simulation: differences a color blind person can't perceive are tripled,
+    /// so a person with normal vision can get an intuition of what's lost by comparing it with
+    /// the unmodified image.
+    Emphasize,
+    /// The original image, tinted with `color` where the simulation differs from it.
+    ///
+    /// The tint's opacity is the distance between the original and simulated colors, multiplied by
+    /// `scale` and by `color`'s alpha, so problem areas stand out without hiding the scene.
+    /// This is easier to read for reviewers than [`Visualize::Residual`], since the scene stays recognizable.
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_color_blindness::*;
+    /// let camera = ColorBlindnessCamera {
+    ///     mode: ColorBlindnessMode::Deuteranopia,
+    ///     enabled: true,
+    ///     visualize: Visualize::Overlay {
+    ///         color: Color::rgba(1.0, 0.0, 0.0, 0.8),
+    ///         scale: 2.0,
+    ///     },
+    ///     ..default()
+    /// };
+    /// ```
+    Overlay {
+        /// Color of the tint
+        color: Color,
+        /// How strongly differences show up, a difference of `1.0 / scale` or more is fully tinted
+        scale: f32,
+    },
+}
+
+/// How to handle output values outside of the `0.0..=1.0` range.
+///
+/// These can happen after applying percentages whose rows don't add up to 1.
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub enum Clamping {
+    /// Clamp each channel to `0.0..=1.0` on regular cameras, and only negative channels on HDR cameras.
+    ///
+    /// This matches the behavior from before clamping could be configured: regular targets
+    /// can't store values out of range anyway, and HDR targets keep values above 1.
+    #[default]
+    Hard,
+    /// Smoothly compress values approaching 1, similar to a tonemapping curve.
+    ///
+    /// Keeps some differences between bright colors that hard clamping would flatten.
+    Soft,
+    /// Scale the whole color down so its brightest channel is at most 1, preserving its hue.
+    Normalize,
 }
 
 /// Helper component to:
 /// - easily switch on and off the `ColorBlindnessPostProcess` effect
 /// - cache current mode
 /// - easily apply different modes onto the `ColorBlindnessPostProcess`
-#[derive(Component, Default)]
+///
+/// Adding it to a camera is all that's needed, `ColorBlindnessPostProcess` gets inserted automatically.
+/// Note that the default values don't change the image: set `enabled` to `true` and
+/// pick a `mode` other than `ColorBlindnessMode::Normal` to see the simulation.
+/// If every camera is still disabled a few seconds after the first one is added,
+/// [`ColorBlindnessPlugin`] logs a warning saying so.
+#[derive(Component)]
 pub struct ColorBlindnessCamera {
     /// Selects the color blindness mode to use
     ///
@@ -277,6 +813,208 @@ pub struct ColorBlindnessCamera {
     pub mode: ColorBlindnessMode,
     /// Controls whether color blindness simulation is enabled
     ///
-    /// Defaults to `false`
+    /// Defaults to `false`, so inserting a camera has no visible effect until it's enabled
     pub enabled: bool,
+    /// Selects what to output while the simulation is enabled
+    ///
+    /// Defaults to `Visualize::Simulation`
+    pub visualize: Visualize,
+    /// Selects the luminance weights used by monochrome modes
+    ///
+    /// Defaults to `LuminanceStandard::Rec601`
+    pub luminance: LuminanceStandard,
+    /// Selects how to handle output values that are out of range
+    ///
+    /// Defaults to `Clamping::Hard`
+    pub clamping: Clamping,
+    /// How strongly the mode is simulated, from `0.0` (normal vision) to `1.0` (the full mode)
+    ///
+    /// Values in between blend the mode's percentages with normal vision,
+    /// which is useful for the spectrum of anomalous trichromacies.
+    ///
+    /// Like every other field, changing it is picked up by the plugin through change detection,
+    /// so the post-process is only updated on frames where the camera changes.
+    ///
+    /// Defaults to `1.0`
+    pub severity: f32,
+    /// How strongly the simulation applies to each output channel, from `0.0` to `1.0`, on top of `severity`
+    ///
+    /// Allows matching a specific person's partial deficiency, e.g. `Vec3::new(0.8, 0.5, 1.0)`
+    /// applies the simulation to the red channel at 80% and the green channel at 50%.
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_color_blindness::*;
+    /// let camera = ColorBlindnessCamera {
+    ///     mode: ColorBlindnessMode::Deuteranopia,
+    ///     enabled: true,
+    ///     strength: Vec3::new(1.0, 0.0, 1.0),
+    ///     ..default()
+    /// };
+    ///
+    /// let percentages = camera.percentages();
+    /// assert_eq!(percentages.red, ColorBlindnessMode::Deuteranopia.percentages().red);
+    /// assert_eq!(percentages.green, Vec3::Y);
+    /// ```
+    ///
+    /// Defaults to `Vec3::ONE`
+    pub strength: Vec3,
+    /// Draws a row of reference swatches along the bottom of the viewport while the effect is active
+    ///
+    /// Each swatch shows a known sRGB color (red, green, blue, yellow, cyan, magenta, white, and
+    /// mid gray) unchanged on its left half, and as simulated on its right half. The unchanged halves
+    /// are fixed anchors for checking a display against the simulation, e.g. when matching it to a
+    /// printed color vision test chart.
+    ///
+    /// Defaults to `false`
+    pub calibration: bool,
+}
+
+impl Default for ColorBlindnessCamera {
+    fn default() -> Self {
+        Self {
+            mode: default(),
+            enabled: false,
+            visualize: default(),
+            luminance: default(),
+            clamping: default(),
+            severity: 1.0,
+            strength: Vec3::ONE,
+            calibration: false,
+        }
+    }
+}
+
+impl ColorBlindnessCamera {
+    /// Creates a camera simulating what `settings` describe, with default rendering options.
+    pub fn from_settings(settings: &ColorBlindnessSettings) -> Self {
+        let mut camera = Self::default();
+        settings.apply_to(&mut camera);
+        camera
+    }
+
+    /// Returns what this camera simulates, to save it and restore it later with
+    /// [`ColorBlindnessCamera::from_settings`] or [`ColorBlindnessSettings::apply_to`].
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_color_blindness::*;
+    /// let camera = ColorBlindnessCamera {
+    ///     mode: ColorBlindnessMode::Monochromacy { residual: 0.2 },
+    ///     enabled: true,
+    ///     severity: 0.7,
+    ///     strength: Vec3::new(1.0, 0.5, 1.0),
+    ///     ..default()
+    /// };
+    ///
+    /// let restored = ColorBlindnessCamera::from_settings(&camera.to_settings());
+    /// assert_eq!(restored.to_settings(), camera.to_settings());
+    /// assert_eq!(restored.percentages(), camera.percentages());
+    /// ```
+    pub fn to_settings(&self) -> ColorBlindnessSettings {
+        ColorBlindnessSettings {
+            mode: self.mode.clone(),
+            enabled: self.enabled,
+            severity: self.severity,
+            strength: self.strength,
+        }
+    }
+
+    /// Returns the percentages this camera applies while enabled, taking `severity` and `strength` into account.
+    ///
+    /// `enabled` doesn't affect them: disabled cameras skip the post-process entirely instead,
+    /// see [`ColorBlindnessCamera::is_effect_active`].
+    pub fn percentages(&self) -> ColorBlindnessPercentages {
+        let percentages = self.mode.percentages_with_luminance(self.luminance);
+
+        // blending each row is the same as blending each output channel in the shader,
+        // but keeps the percentages used on the GPU and the CPU the same
+        let strength = (self.strength * self.severity).clamp(Vec3::ZERO, Vec3::ONE);
+        // written out instead of using `Vec3::lerp`, so full strength gives exactly the mode's percentages
+        let normal = ColorBlindnessPercentages::default();
+        let blend =
+            |normal: Vec3, row: Vec3, strength: f32| normal * (1.0 - strength) + row * strength;
+        ColorBlindnessPercentages::new(
+            blend(normal.red, percentages.red, strength.x),
+            blend(normal.green, percentages.green, strength.y),
+            blend(normal.blue, percentages.blue, strength.z),
+        )
+    }
+
+    /// Changes `severity` by `step`, staying within `0.0..=1.0`.
+    ///
+    /// Steps past a bound stop at it, and stepping again from the bound wraps around to the other one,
+    /// so repeatedly pressing a key goes through the whole range, including both ends.
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_color_blindness::*;
+    /// let mut camera = ColorBlindnessCamera {
+    ///     severity: 0.75,
+    ///     ..default()
+    /// };
+    ///
+    /// camera.cycle_severity(0.5);
+    /// assert_eq!(camera.severity, 1.0);
+    /// camera.cycle_severity(0.5);
+    /// assert_eq!(camera.severity, 0.0);
+    ///
+    /// camera.cycle_severity(-0.5);
+    /// assert_eq!(camera.severity, 1.0);
+    /// camera.cycle_severity(-0.5);
+    /// assert_eq!(camera.severity, 0.5);
+    /// ```
+    pub fn cycle_severity(&mut self, step: f32) {
+        self.severity = if step > 0.0 && self.severity >= 1.0 {
+            0.0
+        } else if step < 0.0 && self.severity <= 0.0 {
+            1.0
+        } else {
+            (self.severity + step).clamp(0.0, 1.0)
+        };
+    }
+
+    /// Returns `true` if this camera actually changes the image,
+    /// meaning it's enabled and its percentages differ from normal vision.
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_color_blindness::*;
+    /// let camera = |mode, enabled| ColorBlindnessCamera {
+    ///     mode,
+    ///     enabled,
+    ///     ..default()
+    /// };
+    ///
+    /// assert!(camera(ColorBlindnessMode::Protanopia, true).is_effect_active());
+    /// assert!(!camera(ColorBlindnessMode::Protanopia, false).is_effect_active());
+    /// assert!(!camera(ColorBlindnessMode::Normal, true).is_effect_active());
+    /// assert!(!camera(ColorBlindnessMode::Normal, false).is_effect_active());
+    ///
+    /// // disabling a camera doesn't change its percentages, only whether they're applied
+    /// assert_eq!(
+    ///     camera(ColorBlindnessMode::Protanopia, false).percentages(),
+    ///     ColorBlindnessMode::Protanopia.percentages(),
+    /// );
+    /// ```
+    pub fn is_effect_active(&self) -> bool {
+        self.enabled && self.percentages() != ColorBlindnessPercentages::default()
+    }
+}
+
+/// Run condition which returns `true` if any [`ColorBlindnessCamera`] has an active effect.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_color_blindness::*;
+/// # #[derive(Component)]
+/// # struct SimulationIndicator;
+/// fn show_indicator(mut indicators: Query<&mut Visibility, With<SimulationIndicator>>) {
+///     // ...
+/// }
+///
+/// App::new().add_systems(Update, show_indicator.run_if(any_color_blindness_active));
+/// ```
+pub fn any_color_blindness_active(cameras: Query<&ColorBlindnessCamera>) -> bool {
+    cameras.iter().any(ColorBlindnessCamera::is_effect_active)
 }
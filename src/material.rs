@@ -0,0 +1,74 @@
+use bevy::{
+    prelude::*,
+    reflect::{TypePath, TypeUuid},
+    render::render_resource::{AsBindGroup, ShaderRef},
+    sprite::Material2d,
+};
+
+use crate::{ColorBlindnessMode, ColorBlindnessPercentages};
+
+/// Path of the shader used by [`ColorBlindnessMaterial`], relative to the assets folder.
+pub const MATERIAL_SHADER_ASSET_PATH: &str = "shaders/color_blindness_material.wgsl";
+
+/// 2d material which draws a texture as seen under a [`ColorBlindnessMode`].
+///
+/// Unlike [`ColorBlindnessCamera`](crate::ColorBlindnessCamera), which simulates the whole view,
+/// this only affects the meshes using it, e.g. a preview thumbnail shown next to the regular image.
+/// It uses the same percentages as the post-process, applied to the texture's linear values.
+///
+/// The material needs `Material2dPlugin::<ColorBlindnessMaterial>` to be added to the app, and the
+/// shader at [`MATERIAL_SHADER_ASSET_PATH`] in the assets folder:
+///
+/// ```rust,no_run
+/// # use bevy::{prelude::*, sprite::{Material2dPlugin, MaterialMesh2dBundle}};
+/// # use bevy_color_blindness::*;
+/// fn setup(
+///     mut commands: Commands,
+///     asset_server: Res<AssetServer>,
+///     mut meshes: ResMut<Assets<Mesh>>,
+///     mut materials: ResMut<Assets<ColorBlindnessMaterial>>,
+/// ) {
+///     commands.spawn(Camera2dBundle::default());
+///     commands.spawn(MaterialMesh2dBundle {
+///         mesh: meshes.add(shape::Quad::new(Vec2::splat(128.0)).into()).into(),
+///         material: materials.add(ColorBlindnessMaterial::new(
+///             asset_server.load("thumbnail.png"),
+///             &ColorBlindnessMode::Protanopia,
+///         )),
+///         ..default()
+///     });
+/// }
+///
+/// App::new()
+///     .add_plugins(DefaultPlugins)
+///     .add_plugin(Material2dPlugin::<ColorBlindnessMaterial>::default())
+///     .add_systems(Startup, setup)
+///     .run();
+/// ```
+#[derive(AsBindGroup, TypeUuid, TypePath, Debug, Clone)]
+#[uuid = "5c6f3a7e-2b1d-4e8a-9f0c-7d4b1a2e6c35"]
+pub struct ColorBlindnessMaterial {
+    /// Percentages applied to the texture's colors
+    #[uniform(0)]
+    pub percentages: ColorBlindnessPercentages,
+    /// Texture to draw
+    #[texture(1)]
+    #[sampler(2)]
+    pub texture: Handle<Image>,
+}
+
+impl ColorBlindnessMaterial {
+    /// Creates a material drawing `texture` as seen under `mode`.
+    pub fn new(texture: Handle<Image>, mode: &ColorBlindnessMode) -> Self {
+        Self {
+            percentages: mode.percentages(),
+            texture,
+        }
+    }
+}
+
+impl Material2d for ColorBlindnessMaterial {
+    fn fragment_shader() -> ShaderRef {
+        MATERIAL_SHADER_ASSET_PATH.into()
+    }
+}
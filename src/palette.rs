@@ -0,0 +1,253 @@
+use bevy::prelude::*;
+
+use crate::{ColorBlindnessMode, ColorBlindnessPercentages};
+
+/// Returns the [CIE76](https://en.wikipedia.org/wiki/Color_difference#CIE76) color difference (ΔE)
+/// between `a` and `b`, computed in CIELAB with a D65 white point.
+///
+/// A ΔE of about 2.3 is a just noticeable difference, and colors further than 10 apart are
+/// easily told apart at a glance. Channels outside of `0.0..=1.0` are clamped first,
+/// like they would be on screen.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_color_blindness::*;
+/// assert_eq!(delta_e(Color::RED, Color::RED), 0.0);
+/// assert!((delta_e(Color::BLACK, Color::WHITE) - 100.0).abs() < 0.1);
+/// ```
+pub fn delta_e(a: Color, b: Color) -> f32 {
+    to_lab(a).distance(to_lab(b))
+}
+
+/// Converts `color` to CIELAB, with a D65 white point.
+fn to_lab(color: Color) -> Vec3 {
+    let [r, g, b, _] = color.as_linear_rgba_f32().map(|c| c.clamp(0.0, 1.0));
+    let xyz = Vec3::new(
+        0.4124 * r + 0.3576 * g + 0.1805 * b,
+        0.2126 * r + 0.7152 * g + 0.0722 * b,
+        0.0193 * r + 0.1192 * g + 0.9505 * b,
+    ) / Vec3::new(0.95047, 1.0, 1.08883);
+
+    let f = |t: f32| {
+        if t > 216.0 / 24389.0 {
+            t.cbrt()
+        } else {
+            (24389.0 / 27.0 * t + 16.0) / 116.0
+        }
+    };
+    let (x, y, z) = (f(xyz.x), f(xyz.y), f(xyz.z));
+    Vec3::new(116.0 * y - 16.0, 500.0 * (x - y), 200.0 * (y - z))
+}
+
+/// Suggests `n` colors that stay as distinguishable as possible under all of `modes`,
+/// as well as under normal vision.
+///
+/// Colors are picked from a fixed set of candidates, covering every 15° of hue at a few
+/// saturations and lightnesses. The first color is the candidate furthest from mid gray, and
+/// every following one is the candidate whose smallest [`delta_e`] to the already picked colors,
+/// under any of the modes, is largest. This greedy search is deterministic: the same arguments
+/// always give the same palette.
+///
+/// It runs in `O(n · candidates · picked · modes)` color comparisons in total, so it's meant for tooling
+/// rather than for running every frame. Asking for more colors than there are candidates
+/// returns every candidate.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_color_blindness::*;
+/// let palette = suggest_palette(4, &[ColorBlindnessMode::Deuteranopia, ColorBlindnessMode::Protanopia]);
+/// assert_eq!(palette.len(), 4);
+/// ```
+pub fn suggest_palette(n: usize, modes: &[ColorBlindnessMode]) -> Vec<Color> {
+    let mut percentages: Vec<ColorBlindnessPercentages> =
+        modes.iter().map(ColorBlindnessMode::percentages).collect();
+    percentages.push(ColorBlindnessMode::Normal.percentages());
+
+    let mut candidates = vec![];
+    for lightness in [0.3, 0.5, 0.7] {
+        for saturation in [0.5, 1.0] {
+            for hue in 0..24 {
+                candidates.push(Color::hsl(hue as f32 * 15.0, saturation, lightness).as_rgba());
+            }
+        }
+    }
+
+    // lab values of every candidate under every set of percentages
+    let simulated: Vec<Vec<Vec3>> = candidates
+        .iter()
+        .map(|c| percentages.iter().map(|p| to_lab(p.apply(*c))).collect())
+        .collect();
+
+    let gray = to_lab(Color::rgb(0.5, 0.5, 0.5));
+    let mut picked: Vec<usize> = vec![];
+    while picked.len() < n.min(candidates.len()) {
+        let score = |candidate: usize| -> f32 {
+            if picked.is_empty() {
+                return simulated[candidate]
+                    .iter()
+                    .map(|lab| lab.distance(gray))
+                    .fold(f32::INFINITY, f32::min);
+            }
+            picked
+                .iter()
+                .flat_map(|&other| {
+                    simulated[candidate]
+                        .iter()
+                        .zip(&simulated[other])
+                        .map(|(a, b)| a.distance(*b))
+                })
+                .fold(f32::INFINITY, f32::min)
+        };
+
+        let mut best: Option<(usize, f32)> = None;
+        for candidate in (0..candidates.len()).filter(|c| !picked.contains(c)) {
+            let candidate_score = score(candidate);
+            if best.map_or(true, |(_, best)| candidate_score > best) {
+                best = Some((candidate, candidate_score));
+            }
+        }
+        match best {
+            Some((candidate, _)) => picked.push(candidate),
+            None => break,
+        }
+    }
+
+    picked.into_iter().map(|i| candidates[i]).collect()
+}
+
+/// Returns a color which looks as similar as possible to `color` under `mode`,
+/// while being as different as possible with normal vision.
+///
+/// The partner is found by moving `color` along the direction `mode` is least sensitive to,
+/// as far as it can go while staying in the sRGB gamut. For dichromacies, that's the
+/// confusion line through `color`, so both look the same; for anomalous trichromacies,
+/// they only look similar. Alpha is kept.
+///
+/// Returns `None` when no other color looks like `color`: for [`ColorBlindnessMode::Normal`],
+/// for colors on the edge of the gamut where the confusion line immediately leaves it
+/// (like black and white), and for [`ColorBlindnessMode::Achromatopsia`] and
+/// `Monochromacy { residual: 0.0 }`, where every color of the same luminance looks the same
+/// so there's no single partner to pick. [`ColorBlindnessMode::Monochromacy`] with a residual
+/// above `0.0` keeps the blue-yellow signal, so like a dichromacy it only confuses colors along
+/// a single direction, and has a partner.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_color_blindness::*;
+/// let mode = ColorBlindnessMode::Protanopia;
+/// let gray = Color::rgb(0.5, 0.5, 0.5);
+/// let partner = confusion_partner(&mode, gray).unwrap();
+///
+/// let percentages = mode.percentages();
+/// assert!(delta_e(percentages.apply(gray), percentages.apply(partner)) < 2.3);
+/// assert!(delta_e(gray, partner) > 20.0);
+///
+/// assert_eq!(confusion_partner(&ColorBlindnessMode::Normal, gray), None);
+/// assert_eq!(confusion_partner(&ColorBlindnessMode::Achromatopsia, gray), None);
+/// assert_eq!(confusion_partner(&mode, Color::BLACK), None);
+/// ```
+pub fn confusion_partner(mode: &ColorBlindnessMode, color: Color) -> Option<Color> {
+    let percentages = mode.percentages();
+    let matrix = Mat3::from_cols(percentages.red, percentages.green, percentages.blue).transpose();
+    // the eigenvectors of MᵀM are the directions in which M scales colors the least and the most
+    let normal = matrix.transpose() * matrix;
+    let trace = normal.x_axis.x + normal.y_axis.y + normal.z_axis.z;
+
+    let (largest, _) = power_iteration(normal);
+    // shifting by the trace turns the smallest eigenvalue into the largest one
+    let (direction, shifted) = power_iteration(Mat3::from_diagonal(Vec3::splat(trace)) - normal);
+    let smallest = trace - shifted;
+    let middle = trace - largest - smallest;
+
+    if smallest > 0.9 || middle < 1e-3 {
+        // nothing is confused, or a whole plane of colors is
+        return None;
+    }
+
+    let [r, g, b, a] = color.as_linear_rgba_f32();
+    let rgb = Vec3::new(r, g, b);
+    // how far `rgb` can move along `direction`, forwards and backwards, while staying in gamut
+    let mut forwards = f32::INFINITY;
+    let mut backwards = f32::INFINITY;
+    for (value, step) in rgb.to_array().into_iter().zip(direction.to_array()) {
+        if step > 0.0 {
+            forwards = forwards.min((1.0 - value) / step);
+            backwards = backwards.min(value / step);
+        } else if step < 0.0 {
+            forwards = forwards.min(value / -step);
+            backwards = backwards.min((1.0 - value) / -step);
+        }
+    }
+
+    let t = if forwards >= backwards {
+        forwards
+    } else {
+        -backwards
+    };
+    if t.abs() < 1e-3 {
+        return None;
+    }
+
+    let partner = (rgb + direction * t).clamp(Vec3::ZERO, Vec3::ONE);
+    Some(Color::rgba_linear(partner.x, partner.y, partner.z, a).as_rgba())
+}
+
+/// Returns the unit eigenvector with the largest eigenvalue of the symmetric matrix `matrix`,
+/// along with that eigenvalue.
+fn power_iteration(matrix: Mat3) -> (Vec3, f32) {
+    // any vector which isn't orthogonal to the eigenvector works, so avoid the axes
+    let mut vector = Vec3::new(0.6, -0.7, 0.4).normalize();
+    for _ in 0..200 {
+        let next = matrix * vector;
+        if next.length_squared() < 1e-20 {
+            return (vector, 0.0);
+        }
+        vector = next.normalize();
+    }
+    (vector, vector.dot(matrix * vector))
+}
+
+/// Returns whether every pair of `colors` stays at least `threshold` apart under the common
+/// dichromacies and anomalous trichromacies, for use as a check in tests.
+///
+/// `threshold` is a [`delta_e`] value: about 2.3 is just noticeable, and 10 or more is easily
+/// told apart at a glance. The modes checked are [`ColorBlindnessMode::Protanopia`],
+/// [`ColorBlindnessMode::Protanomaly`], [`ColorBlindnessMode::Deuteranopia`],
+/// [`ColorBlindnessMode::Deuteranomaly`], [`ColorBlindnessMode::Tritanopia`], and
+/// [`ColorBlindnessMode::Tritanomaly`], as well as normal vision, so colors which are already
+/// too close look the same for everyone also fail. The anomalous modes are checked on their own,
+/// since their matrices aren't a blend of normal vision and the dichromacy, so a pair can be far
+/// enough apart for a protanope and still too close for a protanomalous viewer.
+/// Monochromatic modes aren't checked, since telling colors apart under them only depends on luminance.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_color_blindness::*;
+/// assert!(is_palette_safe(&[Color::BLACK, Color::WHITE, Color::rgb(1.0, 0.5, 0.0)], 10.0));
+///
+/// // a color and the one protanopes confuse it with
+/// let gray = Color::rgb(0.5, 0.5, 0.5);
+/// let partner = confusion_partner(&ColorBlindnessMode::Protanopia, gray).unwrap();
+/// assert!(!is_palette_safe(&[gray, partner], 10.0));
+/// ```
+pub fn is_palette_safe(colors: &[Color], threshold: f32) -> bool {
+    let modes = [
+        ColorBlindnessMode::Normal,
+        ColorBlindnessMode::Protanopia,
+        ColorBlindnessMode::Protanomaly,
+        ColorBlindnessMode::Deuteranopia,
+        ColorBlindnessMode::Deuteranomaly,
+        ColorBlindnessMode::Tritanopia,
+        ColorBlindnessMode::Tritanomaly,
+    ];
+
+    modes.iter().all(|mode| {
+        let percentages = mode.percentages();
+        let simulated: Vec<Color> = colors.iter().map(|c| percentages.apply(*c)).collect();
+        simulated.iter().enumerate().all(|(i, a)| {
+            simulated[i + 1..]
+                .iter()
+                .all(|b| delta_e(*a, *b) >= threshold)
+        })
+    })
+}
@@ -1,42 +1,592 @@
+use std::borrow::Cow;
+
 use bevy::{
-    core_pipeline::{
-        core_3d,
-        fullscreen_vertex_shader::fullscreen_shader_vertex_state,
-    },
+    core_pipeline::{core_2d, core_3d, fullscreen_vertex_shader::fullscreen_shader_vertex_state},
+    ecs::query::QueryItem,
     prelude::*,
     render::{
+        camera::{CameraUpdateSystem, RenderTarget, Viewport},
         extract_component::{
-            ComponentUniforms, ExtractComponent, ExtractComponentPlugin, UniformComponentPlugin,
+            ComponentUniforms, DynamicUniformIndex, ExtractComponent, ExtractComponentPlugin,
+            UniformComponentPlugin,
         },
-        render_graph::{Node, NodeRunError, RenderGraphApp, RenderGraphContext},
+        render_graph::{Node, NodeRunError, RenderGraph, RenderGraphApp, RenderGraphContext},
         render_resource::{
             BindGroupDescriptor, BindGroupEntry, BindGroupLayout, BindGroupLayoutDescriptor,
             BindGroupLayoutEntry, BindingResource, BindingType, CachedRenderPipelineId,
-            ColorTargetState, ColorWrites, FragmentState, MultisampleState, Operations,
+            ColorTargetState, ColorWrites, FilterMode, FragmentState, MultisampleState, Operations,
             PipelineCache, PrimitiveState, RenderPassColorAttachment, RenderPassDescriptor,
-            RenderPipelineDescriptor, Sampler, SamplerBindingType, SamplerDescriptor, ShaderStages,
-            ShaderType, TextureFormat, TextureSampleType, TextureViewDimension,
+            RenderPipelineDescriptor, Sampler, SamplerBindingType, SamplerDescriptor, ShaderDefVal,
+            ShaderStages, ShaderType, SpecializedRenderPipeline, SpecializedRenderPipelines,
+            TextureFormat, TextureSampleType, TextureViewDimension,
         },
         renderer::{RenderContext, RenderDevice},
         texture::BevyDefault,
-        view::{ExtractedView, ViewTarget},
-        RenderApp,
+        view::{ExtractedView, ExtractedWindows, ViewTarget},
+        Render, RenderApp, RenderSet,
     },
+    window::{PrimaryWindow, WindowRef},
 };
 
-use crate::{ColorBlindnessCamera, ColorBlindnessMode, ColorBlindnessPercentages};
+use crate::{
+    preview::{auto_toggle, revert_previews},
+    Clamping, ColorBlindnessCamera, ColorBlindnessMode, ColorBlindnessPercentages, Visualize,
+};
 
+/// Plugin which sets up the color blindness post-processing pass.
+///
+/// # Ordering
+///
+/// The pass runs in the 2d and 3d core graphs after tonemapping and before
+/// `END_MAIN_PASS_POST_PROCESSING`, so UI is not affected.
+///
+/// Like Bevy's own post-processing, the node reads from and writes to the view through
+/// [`ViewTarget::post_process_write`], which swaps the main texture for every pass.
+/// Other post-process nodes that also use `post_process_write` can be ordered before or after it
+/// and their effects will compose, without any of them losing the image.
+///
+/// [`Gizmos`] are drawn as part of the main pass, in the transparent phase, rather than in
+/// a pass of their own. Since the color blindness pass runs after the main pass, gizmo lines
+/// and shapes are simulated like everything else, so debug color-coding can be checked
+/// without any extra setup (see the `gizmos` example).
+///
+/// # Color space
+///
+/// The pass always runs after the tonemapping node, whatever the camera's [`Tonemapping`](bevy::core_pipeline::tonemapping::Tonemapping) is.
+/// With `Tonemapping::None` the tonemapping node doesn't change the image, but the input is
+/// still in the same space: sampling the view target yields linear values in both cases,
+/// so the simulation doesn't need to adjust its handling of sRGB.
+/// The only difference is that without tonemapping, HDR cameras can have values above 1,
+/// which are handled according to the camera's [`Clamping`].
+///
+/// The pipeline is specialized for the format of each view's main texture,
+/// so it works for both HDR (`Rgba16Float`) and regular (`Rgba8UnormSrgb`) cameras.
+/// The main texture is what the pass writes to, not the surface, so the surface format
+/// the platform picks (`Bgra8UnormSrgb`, `Bgra8Unorm`, ...) doesn't affect it:
+/// the upscaling node converts to it at the end of the graph.
+///
+/// The matrices assume sRGB primaries. Bevy renders in sRGB, and on wide-gamut displays
+/// (e.g. Display-P3 on macOS) the operating system maps the sRGB output to the display's gamut,
+/// so the simulation stays correct there. If a window is configured with a surface format which
+/// can hold wider-than-sRGB values (`Rgba16Float` or `Rgb10a2Unorm`), a warning is logged,
+/// since colors outside of sRGB won't be simulated accurately.
+///
+/// # Transparency and clear color
+///
+/// The pass runs on the camera's main texture, after the main pass has been drawn over
+/// the camera's clear color (or the previous camera's output, with `ClearColorConfig::None`).
+/// Transparent objects are already composited at that point, so the simulated image includes
+/// the background behind them, and no pixel is left undefined.
+/// Alpha is passed through unchanged, so a transparent clear color stays transparent
+/// for whatever composites the target afterwards.
+///
+/// The pass writes all four channels on purpose: [`ViewTarget::post_process_write`] renders
+/// into the *other* main texture, which holds stale contents, so only writing RGB would leave
+/// that texture's old alpha behind instead of preserving the source's.
+/// Copying the source alpha in the shader is what keeps it intact.
+///
+/// # Camera lifetime
+///
+/// Cameras can be spawned and despawned at any time, e.g. during level transitions.
+/// The plugin keeps no per-camera state outside of the camera's own entity: in the render world,
+/// extracted components, uniforms, and specialized pipeline ids live on entities which are
+/// cleared every frame, and bind groups are created for each frame, so nothing stale is left
+/// behind when a camera goes away.
+///
+/// # Scheduling
+///
+/// Everything the render world needs is computed in the main schedule: by default,
+/// [`ColorBlindnessPostProcess`] is inserted and updated in `PostUpdate`
+/// (see [`ColorBlindnessSchedule`]). Extraction only runs once the whole main schedule has
+/// finished, so each frame renders the settings as they were at the end of that frame's
+/// main schedule. Frame limiters and vsync plugins wait outside of the systems computing
+/// the settings, so they don't change which frame a change shows up on.
+/// With the default [`ColorBlindnessSchedule::PostUpdate`], a change made in `Update` is rendered
+/// on the same frame; other schedules can take longer, see their documentation.
+///
+/// # Custom shaders
+///
+/// The simulation shader can be replaced with [`ColorBlindnessPluginBuilder::shader`],
+/// reusing the render graph node and texture plumbing of this plugin.
+/// A custom shader must match the bind group used by the built-in one:
+/// - `@group(0) @binding(0)`: the source texture, a filterable `texture_2d<f32>`
+///   (unfilterable with [`ColorBlindnessSampler::Nearest`])
+/// - `@group(0) @binding(1)`: a filtering `sampler` (non-filtering with [`ColorBlindnessSampler::Nearest`])
+/// - `@group(0) @binding(2)`: a uniform with the layout of [`ColorBlindnessPostProcess`]
+///
+/// and have a `fragment` entry point taking a `FullscreenVertexOutput`.
+///
+/// # Options
+///
+/// `ColorBlindnessPlugin` uses the default options. To change them, add a
+/// [`ConfiguredColorBlindnessPlugin`] built with [`ColorBlindnessPlugin::builder`] instead,
+/// which sets up the same pass. Both share the same plugin name, so adding both,
+/// or either of them twice, panics instead of adding the pass twice.
+#[derive(Default)]
 pub struct ColorBlindnessPlugin;
 
+/// [`ColorBlindnessPlugin`] with options, which sets up the same pass.
+///
+/// Created with [`ColorBlindnessPlugin::builder`]. The default value is the same as [`ColorBlindnessPlugin`].
+#[derive(Default)]
+pub struct ConfiguredColorBlindnessPlugin {
+    shader: Option<Handle<Shader>>,
+    auto_camera: bool,
+    schedule: ColorBlindnessSchedule,
+    after_node: Option<Cow<'static, str>>,
+    defaults: ColorBlindnessDefaults,
+    skip_core_graphs: bool,
+    sampler: ColorBlindnessSampler,
+}
+
+/// Builder for [`ConfiguredColorBlindnessPlugin`], returned by [`ColorBlindnessPlugin::builder`].
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_color_blindness::*;
+/// let plugin = ColorBlindnessPlugin::builder()
+///     .auto_camera(true)
+///     .schedule(ColorBlindnessSchedule::FixedUpdate)
+///     .build();
+///
+/// App::new().add_plugin(plugin);
+/// ```
+#[derive(Default)]
+pub struct ColorBlindnessPluginBuilder {
+    plugin: ConfiguredColorBlindnessPlugin,
+}
+
+/// Schedule in which [`ColorBlindnessPlugin`] recomputes the percentages of changed cameras.
+///
+/// Whichever is used, cameras get their [`ColorBlindnessPostProcess`] with up to date
+/// percentages on the same frame they are spawned.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub enum ColorBlindnessSchedule {
+    /// Recompute in `PostUpdate`.
+    ///
+    /// Changes made to a [`ColorBlindnessCamera`] anywhere in `Update` are always rendered on the same frame.
+    #[default]
+    PostUpdate,
+    /// Recompute in `Update`.
+    ///
+    /// Changes made by systems which aren't ordered before the plugin's can take an extra frame to show up.
+    Update,
+    /// Recompute in `FixedUpdate`, for games tying accessibility changes to fixed timestep logic.
+    ///
+    /// Changes are only picked up on frames where the fixed timestep runs. On frames where it doesn't,
+    /// the effect keeps rendering the previous settings, so a change made in `Update` can take
+    /// one or more frames to show up.
+    FixedUpdate,
+}
+
+impl ColorBlindnessPlugin {
+    /// Returns a [`ColorBlindnessPluginBuilder`], to configure all of the plugin's options in one place.
+    pub fn builder() -> ColorBlindnessPluginBuilder {
+        ColorBlindnessPluginBuilder::default()
+    }
+}
+
+impl ColorBlindnessPluginBuilder {
+    /// Uses `shader` for the post-process pass instead of the built-in simulation shader.
+    ///
+    /// See [Custom shaders](ColorBlindnessPlugin#custom-shaders) for what the shader must provide.
+    ///
+    /// ```rust,no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_color_blindness::*;
+    /// # let mut app = App::new();
+    /// let shader = app.world.resource::<AssetServer>().load("shaders/spectral_model.wgsl");
+    /// app.add_plugin(ColorBlindnessPlugin::builder().shader(shader).build());
+    /// ```
+    pub fn shader(mut self, shader: Handle<Shader>) -> Self {
+        self.plugin.shader = Some(shader);
+        self
+    }
+
+    /// Whether to automatically add a [`ColorBlindnessCamera`] to the camera with the highest `order`
+    /// rendering to the primary window, if no camera rendering there has one yet.
+    ///
+    /// In layered setups (e.g. UI over 3D), that's the camera that renders the final composite,
+    /// so this saves figuring out which camera to tag. The chosen camera is logged.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// ```rust,no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_color_blindness::*;
+    /// fn enable(mut cameras: Query<&mut ColorBlindnessCamera>) {
+    ///     for mut camera in &mut cameras {
+    ///         camera.mode = ColorBlindnessMode::Protanopia;
+    ///         camera.enabled = true;
+    ///     }
+    /// }
+    ///
+    /// App::new()
+    ///     .add_plugins(DefaultPlugins)
+    ///     .add_plugin(ColorBlindnessPlugin::builder().auto_camera(true).build())
+    ///     .add_systems(Update, enable)
+    ///     .run();
+    /// ```
+    pub fn auto_camera(mut self, auto_camera: bool) -> Self {
+        self.plugin.auto_camera = auto_camera;
+        self
+    }
+
+    /// Selects the schedule in which percentages are recomputed when a [`ColorBlindnessCamera`] changes.
+    ///
+    /// Defaults to [`ColorBlindnessSchedule::PostUpdate`].
+    pub fn schedule(mut self, schedule: ColorBlindnessSchedule) -> Self {
+        self.plugin.schedule = schedule;
+        self
+    }
+
+    /// Runs the pass after the render graph node called `node`, e.g. a custom outline pass,
+    /// in addition to the usual ordering described in [Ordering](ColorBlindnessPlugin#ordering).
+    ///
+    /// The edge is added to the 2d and 3d graphs once every plugin has been built,
+    /// so the node can be added by a plugin that comes after this one.
+    /// Graphs which don't have a node with that name are left unchanged, and a warning is logged
+    /// if neither has it. The node must not run after `END_MAIN_PASS_POST_PROCESSING`,
+    /// since that would create a cycle, which makes Bevy panic when running the graph.
+    ///
+    /// ```rust,no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_color_blindness::*;
+    /// App::new()
+    ///     .add_plugins(DefaultPlugins)
+    ///     .add_plugin(ColorBlindnessPlugin::builder().after_node("outline").build());
+    /// ```
+    pub fn after_node(mut self, node: impl Into<Cow<'static, str>>) -> Self {
+        self.plugin.after_node = Some(node.into());
+        self
+    }
+
+    /// Whether to add the pass to the 2d and 3d core graphs.
+    ///
+    /// Apps with custom render graphs can turn this off. Everything else is still set up,
+    /// so [`PostProcessNode`] can be added to any graph with user-specified edges:
+    ///
+    /// ```rust,no_run
+    /// # use bevy::{prelude::*, render::{render_graph::RenderGraphApp, RenderApp}};
+    /// # use bevy_color_blindness::*;
+    /// # let mut app = App::new();
+    /// app.add_plugin(ColorBlindnessPlugin::builder().core_graphs(false).build());
+    ///
+    /// let render_app = app.sub_app_mut(RenderApp);
+    /// render_app
+    ///     .add_render_graph_node::<PostProcessNode>("my_graph", PostProcessNode::NAME)
+    ///     .add_render_graph_edges("my_graph", &["my_main_pass", PostProcessNode::NAME, "my_ui_pass"]);
+    /// ```
+    ///
+    /// [`ColorBlindnessPluginBuilder::after_node`] only affects the core graphs, so it has no effect
+    /// when this is off.
+    ///
+    /// Defaults to `true`.
+    pub fn core_graphs(mut self, core_graphs: bool) -> Self {
+        self.plugin.skip_core_graphs = !core_graphs;
+        self
+    }
+
+    /// Selects how the pass samples the view's texture.
+    ///
+    /// Defaults to [`ColorBlindnessSampler::Filtering`]. See [`ColorBlindnessSampler::Nearest`]
+    /// for why pixel tests should use the nearest path.
+    pub fn sampler(mut self, sampler: ColorBlindnessSampler) -> Self {
+        self.plugin.sampler = sampler;
+        self
+    }
+
+    /// Sets the [`ColorBlindnessCamera::mode`] new cameras start with,
+    /// instead of setting it on every camera.
+    ///
+    /// Cameras still start disabled, so the mode is only visible once they are enabled.
+    /// Only cameras built with [`ColorBlindnessDefaults::camera`] inherit it.
+    pub fn default_mode(mut self, mode: ColorBlindnessMode) -> Self {
+        self.plugin.defaults.mode = mode;
+        self
+    }
+
+    /// Sets the [`ColorBlindnessCamera::severity`] new cameras start with,
+    /// instead of setting it on every camera.
+    ///
+    /// Only cameras built with [`ColorBlindnessDefaults::camera`] inherit it.
+    pub fn default_severity(mut self, severity: f32) -> Self {
+        self.plugin.defaults.severity = severity;
+        self
+    }
+
+    /// Sets the [`ColorBlindnessCamera::strength`] new cameras start with,
+    /// instead of setting it on every camera.
+    ///
+    /// Only cameras built with [`ColorBlindnessDefaults::camera`] inherit it.
+    pub fn default_strength(mut self, strength: Vec3) -> Self {
+        self.plugin.defaults.strength = strength;
+        self
+    }
+
+    /// Returns the [`ConfiguredColorBlindnessPlugin`], to add to the app.
+    pub fn build(self) -> ConfiguredColorBlindnessPlugin {
+        self.plugin
+    }
+}
+
+/// App-wide defaults for new [`ColorBlindnessCamera`]s, set with [`ColorBlindnessPluginBuilder::default_mode`],
+/// [`ColorBlindnessPluginBuilder::default_severity`] and [`ColorBlindnessPluginBuilder::default_strength`].
+///
+/// Cameras only start with these values when they are built with [`ColorBlindnessDefaults::camera`],
+/// which is also what the plugin uses for the camera it adds itself. Cameras built any other way,
+/// e.g. with [`ColorBlindnessCamera::default`], a preset or [`ColorBlindnessCamera::from_settings`],
+/// keep exactly the values they were given.
+///
+/// The resource can be changed at runtime, which only affects cameras built afterwards.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_color_blindness::*;
+/// fn setup(mut commands: Commands, defaults: Res<ColorBlindnessDefaults>) {
+///     commands.spawn((
+///         Camera3dBundle::default(),
+///         ColorBlindnessCamera {
+///             enabled: true,
+///             ..defaults.camera()
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct ColorBlindnessDefaults {
+    /// Mode for new cameras
+    ///
+    /// Defaults to `ColorBlindnessMode::Normal`
+    pub mode: ColorBlindnessMode,
+    /// Severity for new cameras
+    ///
+    /// Defaults to `1.0`
+    pub severity: f32,
+    /// Per-channel strength for new cameras
+    ///
+    /// Defaults to `Vec3::ONE`
+    pub strength: Vec3,
+}
+
+impl Default for ColorBlindnessDefaults {
+    fn default() -> Self {
+        Self {
+            mode: default(),
+            severity: 1.0,
+            strength: Vec3::ONE,
+        }
+    }
+}
+
+impl ColorBlindnessDefaults {
+    /// Returns a disabled [`ColorBlindnessCamera`] with these defaults,
+    /// and every other field at its default.
+    pub fn camera(&self) -> ColorBlindnessCamera {
+        ColorBlindnessCamera {
+            mode: self.mode.clone(),
+            severity: self.severity,
+            strength: self.strength,
+            ..default()
+        }
+    }
+}
+
+/// How the color blindness pass samples the view's texture, set with [`ColorBlindnessPluginBuilder::sampler`].
+///
+/// Inserted by [`ColorBlindnessPlugin`] in the render world.
+#[derive(Resource, Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub enum ColorBlindnessSampler {
+    /// Binds the texture as filterable, with a filtering sampler.
+    ///
+    /// This is what custom shaders expect by default, and what should be used in production.
+    #[default]
+    Filtering,
+    /// Binds the texture as unfilterable (`TextureSampleType::Float { filterable: false }`),
+    /// with a non-filtering sampler using nearest filtering.
+    ///
+    /// Each output pixel then reads exactly one texel, so results don't depend on how a backend
+    /// implements filtering. Tests comparing rendered pixels against expected values
+    /// (e.g. from [`simulate_image`](crate::simulate_image)) should use this, since filtering
+    /// can introduce tiny differences between backends and drivers which break exact comparisons.
+    /// Custom shaders must not use filtering features, like `textureSampleBias`, with it.
+    Nearest,
+}
+
+/// Render graph labels, for ordering other nodes relative to the color blindness pass.
+///
+/// ```rust,no_run
+/// # use bevy::{core_pipeline::core_3d, prelude::*, render::{render_graph::RenderGraphApp, RenderApp}};
+/// # use bevy_color_blindness::*;
+/// # let mut app = App::new();
+/// let render_app = app.sub_app_mut(RenderApp);
+/// // run `my_node` on the simulated image
+/// render_app.add_render_graph_edges(
+///     core_3d::graph::NAME,
+///     &[graph::node::COLOR_BLINDNESS, "my_node"],
+/// );
+/// ```
+pub mod graph {
+    /// Nodes added by [`ColorBlindnessPlugin`](crate::ColorBlindnessPlugin).
+    pub mod node {
+        /// The color blindness pass, added to both the 2d and 3d core graphs.
+        ///
+        /// This name is stable, and will only change in breaking releases.
+        pub const COLOR_BLINDNESS: &str = "color_blindness";
+    }
+}
+
+/// Path of the built-in simulation shader, relative to the assets folder.
+pub const SHADER_ASSET_PATH: &str = "shaders/color_blindness.wgsl";
+
+/// Handle to the shader used by the post-process pass.
+///
+/// Inserted by [`ColorBlindnessPlugin`] in both the main and the render world.
+#[derive(Resource, Clone, Debug)]
+pub struct ColorBlindnessShader(pub Handle<Shader>);
+
+/// Whether the player is seeing a simulation this frame, updated by [`ColorBlindnessPlugin`] in `PostUpdate`.
+///
+/// This is `true` when at least one active camera has a [`ColorBlindnessCamera`] whose effect
+/// changes the image (see [`ColorBlindnessCamera::is_effect_active`]), and which isn't skipped
+/// for having a zero sized viewport, e.g. because its window is minimized.
+///
+/// Useful for HUD indicators. It's only updated when the value changes, so it can be used
+/// with change detection:
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_color_blindness::*;
+/// fn update_indicator(active: Res<ColorBlindnessActive>) {
+///     if active.is_changed() {
+///         info!("color blindness simulation visible: {}", active.0);
+///     }
+/// }
+/// ```
+#[derive(Resource, Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct ColorBlindnessActive(pub bool);
+
+/// Callback tweaking the percentages computed for every [`ColorBlindnessCamera`], before they're used by the post-process.
+///
+/// This is an escape hatch for experimenting with custom matrices, e.g. animating a distortion.
+/// The callback runs whenever a camera changes, and for every camera when the resource is inserted,
+/// replaced, or removed. It runs on the CPU once per camera, so it's cheap, but note that
+/// animating something requires it to run every frame, which can be done by marking the cameras
+/// as changed with [`DetectChangesMut::set_changed`].
+///
+/// Cameras that are disabled, or whose own percentages are normal vision, aren't rendered with the
+/// effect at all, so the callback only has a visible effect on cameras simulating something.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_color_blindness::*;
+/// # let mut app = App::new();
+/// // swap what ends up in the red and blue channels
+/// app.insert_resource(ColorBlindnessPercentagesHook::new(|percentages| {
+///     std::mem::swap(&mut percentages.red, &mut percentages.blue);
+/// }));
+/// ```
+#[derive(Resource)]
+pub struct ColorBlindnessPercentagesHook(
+    pub Box<dyn Fn(&mut ColorBlindnessPercentages) + Send + Sync>,
+);
+
+impl ColorBlindnessPercentagesHook {
+    /// Creates a new `ColorBlindnessPercentagesHook` calling `hook`
+    pub fn new(hook: impl Fn(&mut ColorBlindnessPercentages) + Send + Sync + 'static) -> Self {
+        Self(Box::new(hook))
+    }
+}
+
 /// Component to apply the colorblind effect
 ///
 /// Adding this component to a camera will set up the post-processing pipeline
 /// which simulates color blindness.
 /// This is done by adding a render pass taking the original output texture as input,
 /// Then applying a shader to the whole texture, rendering it to a full screen triangle.
-#[derive(Component, Default, Clone, Copy, ExtractComponent, ShaderType)]
+///
+/// This component is inserted automatically on cameras with a [`ColorBlindnessCamera`],
+/// and kept in sync with it, so it doesn't need to be added manually.
+///
+/// Its layout matches the `Settings` struct in the shader, which the bind group layout
+/// declares as the binding's minimum size, as some backends require:
+///
+/// ```rust
+/// # use bevy::render::render_resource::ShaderType;
+/// # use bevy_color_blindness::*;
+/// // percentages: 3 vec3s padded to 16 bytes each (48), visualize: u32 (4),
+/// // viewport: vec4 aligned to 16 (16 at offset 64), clamping: u32 (4), calibration: u32 (4),
+/// // overlay_color: vec4 aligned to 16 (16 at offset 96), overlay_scale: f32 (4),
+/// // rounded up to the struct's 16 byte alignment
+/// assert_eq!(ColorBlindnessPostProcess::min_size().get(), 128);
+/// ```
+#[derive(Component, Clone, Copy, ShaderType)]
 pub struct ColorBlindnessPostProcess {
     percentages: ColorBlindnessPercentages,
+    /// Index of the [`Visualize`] branch to run in the shader
+    visualize: u32,
+    /// Area of the target covered by the camera's viewport, in uv coordinates,
+    /// stored as `(min.x, min.y, max.x, max.y)`
+    viewport: Vec4,
+    /// Index of the [`Clamping`] method to use in the shader
+    clamping: u32,
+    /// Whether to draw the calibration swatches, `0` or `1`
+    calibration: u32,
+    /// Linear color of [`Visualize::Overlay`]
+    overlay_color: Vec4,
+    /// How strongly differences show up in [`Visualize::Overlay`]
+    overlay_scale: f32,
+}
+
+impl Default for ColorBlindnessPostProcess {
+    fn default() -> Self {
+        Self {
+            percentages: default(),
+            visualize: 0,
+            viewport: Vec4::new(0.0, 0.0, 1.0, 1.0),
+            clamping: 0,
+            calibration: 0,
+            overlay_color: Vec4::ZERO,
+            overlay_scale: 0.0,
+        }
+    }
+}
+
+impl ColorBlindnessPostProcess {
+    /// Returns the percentages the post-process is currently applying.
+    ///
+    /// These are kept in sync with the [`ColorBlindnessCamera`] on the same entity,
+    /// so they already account for its `severity` and `strength`,
+    /// and can be read by other systems without recomputing them.
+    /// Like [`ColorBlindnessCamera::percentages`], they don't change when the camera is disabled,
+    /// since the pass is skipped instead.
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_color_blindness::*;
+    /// fn log_red_row(
+    ///     post_processes: Query<&ColorBlindnessPostProcess, Changed<ColorBlindnessPostProcess>>,
+    /// ) {
+    ///     for post_process in &post_processes {
+    ///         info!("red channel mixes {:?}", post_process.percentages().red);
+    ///     }
+    /// }
+    /// ```
+    pub fn percentages(&self) -> ColorBlindnessPercentages {
+        self.percentages
+    }
+}
+
+impl ExtractComponent for ColorBlindnessPostProcess {
+    type Query = (&'static Self, &'static ColorBlindnessCamera);
+    type Filter = ();
+    type Out = Self;
+
+    fn extract_component((settings, camera): QueryItem<'_, Self::Query>) -> Option<Self::Out> {
+        // Disabled cameras, and cameras which wouldn't change the image, are not extracted,
+        // so the node skips them and they cost nothing in the render world
+        camera.is_effect_active().then_some(*settings)
+    }
 }
 
 /// handle to the color blindness simulation shader
@@ -44,6 +594,23 @@ pub struct ColorBlindnessPostProcess {
 //    HandleUntyped::weak_from_u64(Shader::TYPE_UUID, 3937837360667146578);
 
 impl Plugin for ColorBlindnessPlugin {
+    fn build(&self, app: &mut App) {
+        ConfiguredColorBlindnessPlugin::default().build(app);
+    }
+
+    fn finish(&self, app: &mut App) {
+        ConfiguredColorBlindnessPlugin::default().finish(app);
+    }
+
+    fn name(&self) -> &str {
+        PLUGIN_NAME
+    }
+}
+
+/// name shared by both plugin types, so Bevy refuses to add the pass twice
+const PLUGIN_NAME: &str = "bevy_color_blindness::ColorBlindnessPlugin";
+
+impl Plugin for ConfiguredColorBlindnessPlugin {
     fn build(&self, app: &mut App) {
         // TODO: figure out how to load that
         /*load_internal_asset!(
@@ -63,12 +630,75 @@ impl Plugin for ColorBlindnessPlugin {
             // The settings will also be the data used in the shader.
             // This plugin will prepare the component for the GPU by creating a uniform buffer
             // and writing the data to that buffer every frame.
-            .add_plugin(UniformComponentPlugin::<ColorBlindnessPostProcess>::default());
+            .add_plugin(UniformComponentPlugin::<ColorBlindnessPostProcess>::default())
+            // Keep the main world state up to date even without a renderer,
+            // so systems reading it behave the same on servers and clients.
+            .init_resource::<ColorBlindnessActive>()
+            .insert_resource(self.defaults.clone())
+            .add_systems(
+                Update,
+                (revert_previews, auto_toggle, warn_disabled_cameras),
+            )
+            .add_systems(
+                PostUpdate,
+                (
+                    // Cameras spawned at any point during `Update` get their settings
+                    // in time to be extracted this same frame, including their viewport
+                    insert_post_process.after(CameraUpdateSystem),
+                    // The viewport depends on the target size, which is computed by `CameraUpdateSystem`
+                    update_viewports.after(CameraUpdateSystem),
+                    update_active.after(CameraUpdateSystem),
+                ),
+            );
+
+        #[cfg(feature = "env-config")]
+        add_env_mode(app);
+
+        match self.schedule {
+            ColorBlindnessSchedule::PostUpdate => {
+                // Runs after every `Update` system, so changes are extracted on the same frame
+                app.add_systems(PostUpdate, update_percentages);
+            }
+            ColorBlindnessSchedule::Update => {
+                app.add_systems(
+                    Update,
+                    update_percentages.after(revert_previews).after(auto_toggle),
+                );
+            }
+            ColorBlindnessSchedule::FixedUpdate => {
+                app.add_systems(FixedUpdate, update_percentages);
+            }
+        }
+
+        if self.auto_camera {
+            app.add_systems(Update, attach_to_top_camera);
+        }
+
+        if app.get_sub_app(RenderApp).is_err() {
+            // Headless setups (e.g. servers sharing plugin sets with clients) have no render app,
+            // so there is nothing to apply the effect to
+            warn!("ColorBlindnessPlugin was added to an app without a `RenderApp`, color blindness simulation will have no effect");
+            return;
+        }
+
+        let shader = self
+            .shader
+            .clone()
+            .unwrap_or_else(|| app.world.resource::<AssetServer>().load(SHADER_ASSET_PATH));
+        app.insert_resource(ColorBlindnessShader(shader.clone()));
 
         // We need to get the render app from the main app
-        let Ok(render_app) = app.get_sub_app_mut(RenderApp) else {
+        let render_app = app.sub_app_mut(RenderApp);
+
+        render_app
+            .insert_resource(ColorBlindnessShader(shader))
+            .insert_resource(self.sampler)
+            .add_systems(Render, prepare_pipelines.in_set(RenderSet::Prepare))
+            .add_systems(Render, warn_wide_gamut_surfaces.in_set(RenderSet::Prepare));
+
+        if self.skip_core_graphs {
             return;
-        };
+        }
 
         render_app
             // Bevy's renderer uses a render graph which is a collection of nodes in a directed acyclic graph.
@@ -96,8 +726,19 @@ impl Plugin for ColorBlindnessPlugin {
                     PostProcessNode::NAME,
                     core_3d::graph::node::END_MAIN_PASS_POST_PROCESSING,
                 ],
+            )
+            // 2d cameras run their own graph, so the node is added there too.
+            // The node only samples the resolved color target, so it works the same
+            // regardless of the camera's projection.
+            .add_render_graph_node::<PostProcessNode>(core_2d::graph::NAME, PostProcessNode::NAME)
+            .add_render_graph_edges(
+                core_2d::graph::NAME,
+                &[
+                    core_2d::graph::node::TONEMAPPING,
+                    PostProcessNode::NAME,
+                    core_2d::graph::node::END_MAIN_PASS_POST_PROCESSING,
+                ],
             );
-        app.add_systems(Update, update_percentages);
     }
 
     fn finish(&self, app: &mut App) {
@@ -108,19 +749,73 @@ impl Plugin for ColorBlindnessPlugin {
 
         render_app
             // Initialize the pipeline
-            .init_resource::<PostProcessPipeline>();
+            .init_resource::<PostProcessPipeline>()
+            .init_resource::<SpecializedRenderPipelines<PostProcessPipeline>>();
+
+        // Custom nodes can be added by plugins built after this one, so this edge is added here,
+        // once every plugin has been built
+        if let Some(after_node) = self.after_node.as_ref().filter(|_| !self.skip_core_graphs) {
+            let mut render_graph = render_app.world.resource_mut::<RenderGraph>();
+            let mut found = false;
+            for graph_name in [core_2d::graph::NAME, core_3d::graph::NAME] {
+                let Some(graph) = render_graph.get_sub_graph_mut(graph_name) else {
+                    continue;
+                };
+                if graph.get_node_state(after_node.to_string()).is_err() {
+                    continue;
+                }
+                found = true;
+                if let Err(error) =
+                    graph.try_add_node_edge(after_node.to_string(), PostProcessNode::NAME)
+                {
+                    warn!("Couldn't run the color blindness pass after `{after_node}` in `{graph_name}`: {error}");
+                }
+            }
+            if !found {
+                warn!("The color blindness pass was set to run after `{after_node}`, but no node has that name in the 2d or 3d graphs");
+            }
+        }
+    }
+
+    fn name(&self) -> &str {
+        PLUGIN_NAME
     }
 }
 
-/// The post process node used for the render graph
-struct PostProcessNode {
+/// Render graph node running the color blindness pass.
+///
+/// [`ColorBlindnessPlugin`] adds it to the 2d and 3d core graphs, unless
+/// [`ColorBlindnessPluginBuilder::core_graphs`] is turned off, in which case it can be added to
+/// custom graphs with [`RenderGraphApp::add_render_graph_node`]. The plugin still needs to be
+/// added, since it sets up the pipeline and extracts the settings the node uses.
+///
+/// The node has no slot inputs or outputs. It runs on the graph's view entity, which needs
+/// a [`ViewTarget`] and an [`ExtractedView`], as set up by Bevy for every camera, and a
+/// [`ColorBlindnessPostProcess`], which is extracted from cameras with an active
+/// [`ColorBlindnessCamera`]. Views without one are skipped.
+/// It reads the main texture and writes the simulated image back through
+/// [`ViewTarget::post_process_write`], so it has to run after the main pass has drawn the scene,
+/// and before the main texture is written to the surface.
+pub struct PostProcessNode {
     // The node needs a query to gather data from the ECS in order to do its rendering,
     // but it's not a normal system so we need to define it manually.
-    query: QueryState<&'static ViewTarget, With<ExtractedView>>,
+    // Views without an extracted `ColorBlindnessPostProcess` don't match, so the node skips them.
+    // Cameras without a `ColorBlindnessCamera`, or whose effect wouldn't change the image, are never
+    // extracted, so they only pay for this lookup
+    query: QueryState<
+        (
+            &'static ViewTarget,
+            &'static ExtractedView,
+            &'static PostProcessPipelineId,
+            &'static DynamicUniformIndex<ColorBlindnessPostProcess>,
+        ),
+        With<ColorBlindnessPostProcess>,
+    >,
 }
 
 impl PostProcessNode {
-    pub const NAME: &str = "post_process";
+    /// Name the node is added to the core graphs with, same as [`graph::node::COLOR_BLINDNESS`].
+    pub const NAME: &str = graph::node::COLOR_BLINDNESS;
 }
 
 impl FromWorld for PostProcessNode {
@@ -152,15 +847,25 @@ impl Node for PostProcessNode {
         render_context: &mut RenderContext,
         world: &World,
     ) -> Result<(), NodeRunError> {
+        #[cfg(feature = "trace")]
+        let _color_blindness_span = info_span!("color_blindness").entered();
+
         // Get the entity of the view for the render graph where this node is running
         let view_entity = graph_context.view_entity();
 
         // We get the data we need from the world based on the view entity passed to the node.
         // The data is the query that was defined earlier in the [`PostProcessNode`]
-        let Ok(view_target) = self.query.get_manual(world, view_entity) else {
+        let Ok((view_target, view, pipeline_id, settings_index)) =
+            self.query.get_manual(world, view_entity)
+        else {
             return Ok(());
         };
 
+        // Minimized windows can have a zero sized target, which can't be rendered to
+        if !has_area(view.viewport.zw()) {
+            return Ok(());
+        }
+
         // Get the pipeline resource that contains the global data we need to create the render pipeline
         let post_process_pipeline = world.resource::<PostProcessPipeline>();
 
@@ -168,8 +873,8 @@ impl Node for PostProcessNode {
         // It is required to avoid creating a new pipeline each frame, which is expensive due to shader compilation.
         let pipeline_cache = world.resource::<PipelineCache>();
 
-        // Get the pipeline from the cache
-        let Some(pipeline) = pipeline_cache.get_render_pipeline(post_process_pipeline.pipeline_id) else {
+        // Get the pipeline specialized for this view from the cache
+        let Some(pipeline) = pipeline_cache.get_render_pipeline(pipeline_id.0) else {
             return Ok(());
         };
 
@@ -234,7 +939,8 @@ impl Node for PostProcessNode {
         // This is mostly just wgpu boilerplate for drawing a fullscreen triangle,
         // using the pipeline/bind_group created above
         render_pass.set_render_pipeline(pipeline);
-        render_pass.set_bind_group(0, &bind_group, &[]);
+        // Every view's settings live in the same buffer, so select this view's with its offset
+        render_pass.set_bind_group(0, &bind_group, &[settings_index.index()]);
         render_pass.draw(0..3, 0..1);
 
         Ok(())
@@ -246,11 +952,36 @@ impl Node for PostProcessNode {
 struct PostProcessPipeline {
     layout: BindGroupLayout,
     sampler: Sampler,
-    pipeline_id: CachedRenderPipelineId,
+    shader: Handle<Shader>,
+}
+
+/// Key used to specialize [`PostProcessPipeline`] for each view
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct PostProcessPipelineKey {
+    /// Format of the view's main texture, which the pass writes to
+    texture_format: TextureFormat,
 }
 
+impl PostProcessPipelineKey {
+    /// Returns the shader defs for views with this key
+    fn shader_defs(&self) -> Vec<ShaderDefVal> {
+        if self.texture_format == ViewTarget::TEXTURE_FORMAT_HDR {
+            // lets `Clamping::Hard` keep values above 1, which HDR targets can store
+            vec!["HDR".into()]
+        } else {
+            vec![]
+        }
+    }
+}
+
+/// Id of the pipeline specialized for a view, prepared by `prepare_pipelines`
+#[derive(Component)]
+struct PostProcessPipelineId(CachedRenderPipelineId);
+
 impl FromWorld for PostProcessPipeline {
     fn from_world(world: &mut World) -> Self {
+        let nearest = world.get_resource::<ColorBlindnessSampler>().copied()
+            == Some(ColorBlindnessSampler::Nearest);
         let render_device = world.resource::<RenderDevice>();
 
         // We need to define the bind group layout used for our pipeline
@@ -262,7 +993,9 @@ impl FromWorld for PostProcessPipeline {
                     binding: 0,
                     visibility: ShaderStages::FRAGMENT,
                     ty: BindingType::Texture {
-                        sample_type: TextureSampleType::Float { filterable: true },
+                        sample_type: TextureSampleType::Float {
+                            filterable: !nearest,
+                        },
                         view_dimension: TextureViewDimension::D2,
                         multisampled: false,
                     },
@@ -272,7 +1005,11 @@ impl FromWorld for PostProcessPipeline {
                 BindGroupLayoutEntry {
                     binding: 1,
                     visibility: ShaderStages::FRAGMENT,
-                    ty: BindingType::Sampler(SamplerBindingType::Filtering),
+                    ty: BindingType::Sampler(if nearest {
+                        SamplerBindingType::NonFiltering
+                    } else {
+                        SamplerBindingType::Filtering
+                    }),
                     count: None,
                 },
                 // The settings uniform that will control the effect
@@ -281,8 +1018,9 @@ impl FromWorld for PostProcessPipeline {
                     visibility: ShaderStages::FRAGMENT,
                     ty: BindingType::Buffer {
                         ty: bevy::render::render_resource::BufferBindingType::Uniform,
-                        has_dynamic_offset: false,
-                        min_binding_size: None,
+                        // `UniformComponentPlugin` stores the settings of all views in one buffer
+                        has_dynamic_offset: true,
+                        min_binding_size: Some(ColorBlindnessPostProcess::min_size()),
                     },
                     count: None,
                 },
@@ -290,62 +1028,380 @@ impl FromWorld for PostProcessPipeline {
         });
 
         // We can create the sampler here since it won't change at runtime and doesn't depend on the view
-        let sampler = render_device.create_sampler(&SamplerDescriptor::default());
+        let sampler = render_device.create_sampler(&if nearest {
+            SamplerDescriptor {
+                mag_filter: FilterMode::Nearest,
+                min_filter: FilterMode::Nearest,
+                mipmap_filter: FilterMode::Nearest,
+                ..default()
+            }
+        } else {
+            SamplerDescriptor::default()
+        });
 
         // Get the shader handle
-        let shader = world
-            .resource::<AssetServer>()
-            .load("shaders/color_blindness.wgsl");
-
-        let pipeline_id = world
-            .resource_mut::<PipelineCache>()
-            // This will add the pipeline to the cache and queue it's creation
-            .queue_render_pipeline(RenderPipelineDescriptor {
-                label: Some("post_process_pipeline".into()),
-                layout: vec![layout.clone()],
-                // This will setup a fullscreen triangle for the vertex state
-                vertex: fullscreen_shader_vertex_state(),
-                fragment: Some(FragmentState {
-                    shader,
-                    shader_defs: vec![],
-                    // Make sure this matches the entry point of your shader.
-                    // It can be anything as long as it matches here and in the shader.
-                    entry_point: "fragment".into(),
-                    targets: vec![Some(ColorTargetState {
-                        format: TextureFormat::bevy_default(),
-                        blend: None,
-                        write_mask: ColorWrites::ALL,
-                    })],
-                }),
-                // All of the following property are not important for this effect so just use the default values.
-                // This struct doesn't have the Default trai implemented because not all field can have a default value.
-                primitive: PrimitiveState::default(),
-                depth_stencil: None,
-                multisample: MultisampleState::default(),
-                push_constant_ranges: vec![],
-            });
+        let shader = world.resource::<ColorBlindnessShader>().0.clone();
 
         Self {
             layout,
             sampler,
-            pipeline_id,
+            shader,
+        }
+    }
+}
+
+impl SpecializedRenderPipeline for PostProcessPipeline {
+    type Key = PostProcessPipelineKey;
+
+    fn specialize(&self, key: Self::Key) -> RenderPipelineDescriptor {
+        #[cfg(feature = "trace")]
+        let _specialize_span =
+            info_span!("color_blindness_specialize", format = ?key.texture_format).entered();
+
+        RenderPipelineDescriptor {
+            label: Some("post_process_pipeline".into()),
+            layout: vec![self.layout.clone()],
+            // This will setup a fullscreen triangle for the vertex state
+            vertex: fullscreen_shader_vertex_state(),
+            fragment: Some(FragmentState {
+                shader: self.shader.clone(),
+                shader_defs: key.shader_defs(),
+                // Make sure this matches the entry point of your shader.
+                // It can be anything as long as it matches here and in the shader.
+                entry_point: "fragment".into(),
+                targets: vec![Some(ColorTargetState {
+                    // Both sRGB and float formats are read and written as linear values,
+                    // so the shader doesn't need to know which one it's using
+                    format: key.texture_format,
+                    blend: None,
+                    write_mask: ColorWrites::ALL,
+                })],
+            }),
+            // All of the following property are not important for this effect so just use the default values.
+            // This struct doesn't have the Default trai implemented because not all field can have a default value.
+            primitive: PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: MultisampleState::default(),
+            push_constant_ranges: vec![],
+        }
+    }
+}
+
+/// specializes the pipeline for the main texture format of every view the pass runs on
+fn prepare_pipelines(
+    mut commands: Commands,
+    pipeline_cache: Res<PipelineCache>,
+    mut pipelines: ResMut<SpecializedRenderPipelines<PostProcessPipeline>>,
+    post_process_pipeline: Res<PostProcessPipeline>,
+    views: Query<(Entity, &ExtractedView), With<ColorBlindnessPostProcess>>,
+) {
+    for (entity, view) in &views {
+        let texture_format = if view.hdr {
+            ViewTarget::TEXTURE_FORMAT_HDR
+        } else {
+            TextureFormat::bevy_default()
+        };
+        let pipeline_id = pipelines.specialize(
+            &pipeline_cache,
+            &post_process_pipeline,
+            PostProcessPipelineKey { texture_format },
+        );
+        commands
+            .entity(entity)
+            .insert(PostProcessPipelineId(pipeline_id));
+    }
+}
+
+/// warns once if a window uses a surface format which can hold colors outside of sRGB,
+/// which the matrices aren't designed for
+fn warn_wide_gamut_surfaces(mut warned: Local<bool>, windows: Res<ExtractedWindows>) {
+    if *warned {
+        return;
+    }
+
+    for window in windows.values() {
+        let Some(format) = window.swap_chain_texture_format else {
+            continue;
+        };
+        if matches!(
+            format,
+            TextureFormat::Rgba16Float | TextureFormat::Rgb10a2Unorm
+        ) {
+            warn!("Window {:?} uses the {format:?} surface format, but color blindness simulation assumes sRGB, so colors outside of the sRGB gamut won't be simulated accurately", window.entity);
+            *warned = true;
+        }
+    }
+}
+
+/// adds a `ColorBlindnessCamera` to the highest order camera rendering to the primary window,
+/// unless one of the cameras rendering there already has one
+fn attach_to_top_camera(
+    mut commands: Commands,
+    defaults: Res<ColorBlindnessDefaults>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    cameras: Query<(Entity, &Camera, Option<&ColorBlindnessCamera>)>,
+) {
+    let Ok(primary_window) = primary_window.get_single() else {
+        return;
+    };
+    let renders_to_primary = |camera: &Camera| match &camera.target {
+        RenderTarget::Window(WindowRef::Primary) => true,
+        RenderTarget::Window(WindowRef::Entity(window)) => *window == primary_window,
+        _ => false,
+    };
+
+    let mut top = None;
+    for (entity, camera, color_blindness) in &cameras {
+        if !renders_to_primary(camera) {
+            continue;
+        }
+        if color_blindness.is_some() {
+            return;
+        }
+        if top.map_or(true, |(_, order)| camera.order > order) {
+            top = Some((entity, camera.order));
+        }
+    }
+
+    if let Some((entity, order)) = top {
+        info!("Adding ColorBlindnessCamera to camera {entity:?} with order {order}");
+        commands.entity(entity).insert(defaults.camera());
+    }
+}
+
+/// inserts `ColorBlindnessPostProcess` on cameras which only have a `ColorBlindnessCamera`
+fn insert_post_process(
+    mut commands: Commands,
+    hook: Option<Res<ColorBlindnessPercentagesHook>>,
+    cameras: Query<
+        (Entity, &ColorBlindnessCamera, Option<&Camera>),
+        Without<ColorBlindnessPostProcess>,
+    >,
+) {
+    for (entity, camera, render_camera) in &cameras {
+        // the settings are computed here, since `update_percentages` and `update_viewports`
+        // won't see the component until the commands are applied
+        let mut settings = ColorBlindnessPostProcess::default();
+        apply_camera(&mut settings, camera, hook.as_deref());
+        if let Some(render_camera) = render_camera {
+            settings.viewport = camera_viewport(render_camera);
         }
+        commands.entity(entity).insert(settings);
     }
 }
 
 /// updates the percentages in the post processing material when the values in `ColorBlindnessCamera` change
 fn update_percentages(
-    mut settings: Query<
-        (&mut ColorBlindnessPostProcess, &ColorBlindnessCamera),
-        Changed<ColorBlindnessCamera>,
-    >,
+    hook: Option<Res<ColorBlindnessPercentagesHook>>,
+    mut had_hook: Local<bool>,
+    mut settings: Query<(&mut ColorBlindnessPostProcess, Ref<ColorBlindnessCamera>)>,
 ) {
+    // adding, replacing, or removing the hook affects every camera
+    let hook_changed = hook.as_ref().map_or(*had_hook, |hook| hook.is_changed());
+    *had_hook = hook.is_some();
+
     for (mut settings, camera) in &mut settings {
-        let mode = if camera.enabled {
-            &camera.mode
-        } else {
-            &ColorBlindnessMode::Normal
+        if camera.is_changed() || hook_changed {
+            apply_camera(&mut settings, &camera, hook.as_deref());
+        }
+    }
+}
+
+/// keeps the viewport in `ColorBlindnessPostProcess` in sync with the camera's, so split screen
+/// cameras only apply the effect to their own part of the target
+fn update_viewports(mut cameras: Query<(&Camera, &mut ColorBlindnessPostProcess)>) {
+    for (camera, mut settings) in &mut cameras {
+        let viewport = camera_viewport(camera);
+        // avoid triggering change detection every frame
+        if settings.viewport != viewport {
+            settings.viewport = viewport;
+        }
+    }
+}
+
+/// how long cameras can stay disabled before `warn_disabled_cameras` warns about it
+const DISABLED_WARNING_DELAY: f32 = 5.0;
+
+/// warns once if there are cameras, but all of them have stayed disabled for a few seconds,
+/// which is what happens when `ColorBlindnessCamera::default()` is inserted and left as is
+fn warn_disabled_cameras(
+    mut state: Local<(f32, bool)>,
+    time: Res<Time>,
+    cameras: Query<&ColorBlindnessCamera>,
+) {
+    let (elapsed, done) = &mut *state;
+    if *done || cameras.is_empty() {
+        return;
+    }
+
+    if cameras.iter().any(|camera| camera.enabled) {
+        *done = true;
+        return;
+    }
+
+    *elapsed += time.delta_seconds();
+    if *elapsed >= DISABLED_WARNING_DELAY {
+        warn!("Every `ColorBlindnessCamera` has been disabled for {DISABLED_WARNING_DELAY} seconds, so the simulation isn't visible. Set `enabled` to `true` to turn it on");
+        *done = true;
+    }
+}
+
+/// returns the part of its target `camera` renders to, as `min.xy, max.xy` in uv space
+fn camera_viewport(camera: &Camera) -> Vec4 {
+    viewport_uv(camera.viewport.as_ref(), camera.physical_target_size())
+}
+
+/// returns `viewport` as `min.xy, max.xy` in uv space of a `target` sized target,
+/// or the whole target if either isn't known
+fn viewport_uv(viewport: Option<&Viewport>, target: Option<UVec2>) -> Vec4 {
+    match (viewport, target) {
+        (Some(viewport), Some(target)) if has_area(target) => {
+            let target = target.as_vec2();
+            let min = viewport.physical_position.as_vec2() / target;
+            let max = (viewport.physical_position + viewport.physical_size).as_vec2() / target;
+            Vec4::new(min.x, min.y, max.x, max.y)
+        }
+        _ => Vec4::new(0.0, 0.0, 1.0, 1.0),
+    }
+}
+
+/// returns whether a viewport of `size` has any pixels to run the pass on
+fn has_area(size: UVec2) -> bool {
+    size.x > 0 && size.y > 0
+}
+
+/// updates `ColorBlindnessActive` from the cameras which will run the pass this frame
+fn update_active(
+    mut active: ResMut<ColorBlindnessActive>,
+    cameras: Query<(&Camera, &ColorBlindnessCamera)>,
+) {
+    let is_active = cameras.iter().any(|(camera, color_blindness)| {
+        // the size isn't known until the target has been found, so only a known empty size skips the pass
+        let has_size = camera.physical_viewport_size().map_or(true, has_area);
+        camera.is_active && has_size && color_blindness.is_effect_active()
+    });
+    // avoid triggering change detection every frame
+    if active.0 != is_active {
+        active.0 = is_active;
+    }
+}
+
+/// Name of the environment variable read with the `env-config` feature.
+#[cfg(feature = "env-config")]
+pub const MODE_ENV_VAR: &str = "BEVY_COLORBLINDNESS_MODE";
+
+/// mode read from `MODE_ENV_VAR` at startup
+#[cfg(feature = "env-config")]
+#[derive(Resource)]
+struct EnvMode(crate::ColorBlindnessMode);
+
+/// reads `MODE_ENV_VAR`, and if it's set, applies its mode to every `ColorBlindnessCamera` as it's added
+#[cfg(feature = "env-config")]
+fn add_env_mode(app: &mut App) {
+    let Ok(value) = std::env::var(MODE_ENV_VAR) else {
+        return;
+    };
+    match value.parse() {
+        Ok(mode) => {
+            info!("Simulating {mode:?} on every camera, from {MODE_ENV_VAR}");
+            app.insert_resource(EnvMode(mode)).add_systems(
+                PostUpdate,
+                // before the settings are computed, so the first frame already uses the mode
+                apply_env_mode
+                    .before(insert_post_process)
+                    .before(update_percentages),
+            );
+        }
+        Err(error) => warn!("Ignoring {MODE_ENV_VAR}: {error}"),
+    }
+}
+
+#[cfg(feature = "env-config")]
+fn apply_env_mode(
+    mode: Res<EnvMode>,
+    mut cameras: Query<&mut ColorBlindnessCamera, Added<ColorBlindnessCamera>>,
+) {
+    for mut camera in &mut cameras {
+        camera.mode = mode.0.clone();
+        camera.enabled = true;
+    }
+}
+
+/// writes the values of `camera` into `settings`, running `hook` on the percentages if there is one
+fn apply_camera(
+    settings: &mut ColorBlindnessPostProcess,
+    camera: &ColorBlindnessCamera,
+    hook: Option<&ColorBlindnessPercentagesHook>,
+) {
+    settings.percentages = camera.percentages();
+    if let Some(hook) = hook {
+        (hook.0)(&mut settings.percentages);
+    }
+    settings.visualize = match camera.visualize {
+        Visualize::Simulation => 0,
+        Visualize::Residual => 1,
+        Visualize::MissingEdges => 2,
+        Visualize::Emphasize => 3,
+        Visualize::Overlay { color, scale } => {
+            settings.overlay_color = Vec4::from(color.as_linear_rgba_f32());
+            settings.overlay_scale = scale;
+            4
+        }
+    };
+    settings.clamping = match camera.clamping {
+        Clamping::Hard => 0,
+        Clamping::Soft => 1,
+        Clamping::Normalize => 2,
+    };
+    settings.calibration = camera.calibration as u32;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn viewport_uv_is_relative_to_the_target() {
+        let right_half = Viewport {
+            physical_position: UVec2::new(640, 0),
+            physical_size: UVec2::new(640, 720),
+            ..default()
+        };
+        assert_eq!(
+            viewport_uv(Some(&right_half), Some(UVec2::new(1280, 720))),
+            Vec4::new(0.5, 0.0, 1.0, 1.0)
+        );
+        // until the target size is known, the whole target is used
+        assert_eq!(
+            viewport_uv(Some(&right_half), None),
+            Vec4::new(0.0, 0.0, 1.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn zero_sized_viewports_have_no_area() {
+        assert!(!has_area(UVec2::new(0, 720)));
+        assert!(!has_area(UVec2::new(1280, 0)));
+        assert!(has_area(UVec2::new(1280, 720)));
+    }
+
+    #[test]
+    fn only_hdr_views_keep_values_above_one() {
+        let hdr = PostProcessPipelineKey {
+            texture_format: ViewTarget::TEXTURE_FORMAT_HDR,
         };
-        settings.percentages = mode.percentages();
+        assert_eq!(hdr.shader_defs(), vec![ShaderDefVal::from("HDR")]);
+
+        let sdr = PostProcessPipelineKey {
+            texture_format: TextureFormat::bevy_default(),
+        };
+        assert!(sdr.shader_defs().is_empty());
+    }
+
+    #[test]
+    fn cameras_clamp_hard_by_default() {
+        let mut settings = ColorBlindnessPostProcess::default();
+        apply_camera(&mut settings, &ColorBlindnessCamera::default(), None);
+        assert_eq!(settings.clamping, 0);
     }
 }
@@ -0,0 +1,127 @@
+use bevy::prelude::*;
+
+use crate::{ColorBlindnessCamera, ColorBlindnessMode};
+
+/// Describes what a [`ColorBlindnessCamera`] simulates, without its rendering options.
+///
+/// Returned by [`ColorBlindnessPreset::settings`] and [`ColorBlindnessCamera::to_settings`].
+/// It's small, so it's a good fit for saving the player's accessibility options.
+/// With the `serde` feature, it implements `Serialize` and `Deserialize`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColorBlindnessSettings {
+    /// See [`ColorBlindnessCamera::mode`]
+    pub mode: ColorBlindnessMode,
+    /// See [`ColorBlindnessCamera::enabled`]
+    pub enabled: bool,
+    /// See [`ColorBlindnessCamera::severity`]
+    pub severity: f32,
+    /// See [`ColorBlindnessCamera::strength`]
+    pub strength: Vec3,
+}
+
+impl ColorBlindnessSettings {
+    /// Writes these settings into `camera`, leaving its rendering options untouched.
+    pub fn apply_to(&self, camera: &mut ColorBlindnessCamera) {
+        camera.mode = self.mode.clone();
+        camera.enabled = self.enabled;
+        camera.severity = self.severity;
+        camera.strength = self.strength;
+    }
+}
+
+/// Named starting points representing common real-world profiles.
+///
+/// People with the same condition see colors very differently, especially with anomalous trichromacy,
+/// which ranges from barely noticeable to almost dichromatic ([Machado et al., 2009](https://www.inf.ufrgs.br/~oliveira/pubs_files/CVD_Simulation/CVD_Simulation.html)).
+/// These presets pick representative severities, so testing with a few of them covers most players
+/// without having to know the underlying matrices:
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_color_blindness::*;
+/// let mut camera = ColorBlindnessCamera::default();
+///
+/// // e.g. to fill a menu
+/// for preset in ColorBlindnessPreset::ALL {
+///     println!("{preset:?}");
+/// }
+///
+/// ColorBlindnessPreset::TypicalDeuteranomaly.settings().apply_to(&mut camera);
+/// assert_eq!(camera.mode, ColorBlindnessMode::Deuteranomaly);
+/// assert!(camera.enabled);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorBlindnessPreset {
+    /// Deuteranomaly at half severity.
+    ///
+    /// Many deuteranomalous people only notice their condition on color vision tests,
+    /// so this is a good check that subtle hue differences aren't load-bearing.
+    MildDeuteranomaly,
+    /// Deuteranomaly at full severity.
+    ///
+    /// The most common condition, affecting around 5% of males ([Sharpe et al., 1999](https://www.researchgate.net/publication/238700157_Opsin_genes_cone_photopigments_color_vision_and_color_blindness)).
+    TypicalDeuteranomaly,
+    /// Protanomaly at full severity.
+    ///
+    /// Around 1% of males. Besides confusing reds and greens, reds look darker.
+    TypicalProtanomaly,
+    /// Deuteranopia, complete absence of the M cones.
+    ///
+    /// Around 1% of males.
+    Deuteranopia,
+    /// Protanopia, complete absence of the L cones.
+    ///
+    /// Around 1% of males, and the most severe red-green condition, since reds also lose most of their brightness.
+    Protanopia,
+    /// Tritanopia, complete absence of the S cones.
+    ///
+    /// Rare, but affects both sexes equally, and is the only common blue-yellow condition.
+    Tritanopia,
+    /// Incomplete achromatopsia, with some blue-yellow discrimination left.
+    ///
+    /// Represented by [`ColorBlindnessMode::Monochromacy`] with a residual of `0.1`.
+    IncompleteAchromatopsia,
+    /// Complete achromatopsia, with no color discrimination at all.
+    ///
+    /// The worst case: if the game is playable like this, it doesn't rely on color alone.
+    Achromatopsia,
+}
+
+impl ColorBlindnessPreset {
+    /// Every preset, in the order a settings menu would list them: the common red-green conditions first,
+    /// then the rarer ones.
+    pub const ALL: [ColorBlindnessPreset; 8] = [
+        ColorBlindnessPreset::MildDeuteranomaly,
+        ColorBlindnessPreset::TypicalDeuteranomaly,
+        ColorBlindnessPreset::TypicalProtanomaly,
+        ColorBlindnessPreset::Deuteranopia,
+        ColorBlindnessPreset::Protanopia,
+        ColorBlindnessPreset::Tritanopia,
+        ColorBlindnessPreset::IncompleteAchromatopsia,
+        ColorBlindnessPreset::Achromatopsia,
+    ];
+
+    /// Returns the settings of this preset, with the simulation enabled.
+    pub fn settings(&self) -> ColorBlindnessSettings {
+        let (mode, severity) = match self {
+            ColorBlindnessPreset::MildDeuteranomaly => (ColorBlindnessMode::Deuteranomaly, 0.5),
+            ColorBlindnessPreset::TypicalDeuteranomaly => (ColorBlindnessMode::Deuteranomaly, 1.0),
+            ColorBlindnessPreset::TypicalProtanomaly => (ColorBlindnessMode::Protanomaly, 1.0),
+            ColorBlindnessPreset::Deuteranopia => (ColorBlindnessMode::Deuteranopia, 1.0),
+            ColorBlindnessPreset::Protanopia => (ColorBlindnessMode::Protanopia, 1.0),
+            ColorBlindnessPreset::Tritanopia => (ColorBlindnessMode::Tritanopia, 1.0),
+            ColorBlindnessPreset::IncompleteAchromatopsia => {
+                (ColorBlindnessMode::Monochromacy { residual: 0.1 }, 1.0)
+            }
+            ColorBlindnessPreset::Achromatopsia => (ColorBlindnessMode::Achromatopsia, 1.0),
+        };
+
+        ColorBlindnessSettings {
+            mode,
+            enabled: true,
+            severity,
+            strength: Vec3::ONE,
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{ColorBlindnessCamera, ColorBlindnessMode};
+
+/// Temporarily overrides the [`ColorBlindnessCamera`] on the same entity,
+/// restoring its previous `mode` and `enabled` once the timer runs out.
+///
+/// Usually created through [`ColorBlindnessCommandsExt::preview_color_blindness`](crate::ColorBlindnessCommandsExt::preview_color_blindness).
+/// The component removes itself when the preview ends.
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_color_blindness::*;
+/// let camera = ColorBlindnessCamera::default();
+/// let mut preview = ColorBlindnessPreview::new(&camera, Duration::from_secs(3));
+///
+/// assert!(!preview.tick(Duration::from_secs(2)));
+/// // expires once the full duration has passed
+/// assert!(preview.tick(Duration::from_secs(1)));
+/// ```
+#[derive(Component, Debug)]
+pub struct ColorBlindnessPreview {
+    timer: Timer,
+    previous_mode: ColorBlindnessMode,
+    previous_enabled: bool,
+}
+
+impl ColorBlindnessPreview {
+    /// Creates a preview lasting `duration`, which will restore the current state of `camera` when done.
+    pub fn new(camera: &ColorBlindnessCamera, duration: Duration) -> Self {
+        Self {
+            timer: Timer::new(duration, TimerMode::Once),
+            previous_mode: camera.mode.clone(),
+            previous_enabled: camera.enabled,
+        }
+    }
+
+    /// Returns a preview lasting `duration`, restoring the same state as `self`.
+    pub(crate) fn restarted(&self, duration: Duration) -> Self {
+        Self {
+            timer: Timer::new(duration, TimerMode::Once),
+            previous_mode: self.previous_mode.clone(),
+            previous_enabled: self.previous_enabled,
+        }
+    }
+
+    /// Advances the preview by `delta`, returning `true` once it has expired.
+    pub fn tick(&mut self, delta: Duration) -> bool {
+        self.timer.tick(delta).finished()
+    }
+}
+
+/// restores cameras whose `ColorBlindnessPreview` has expired
+pub(crate) fn revert_previews(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut previews: Query<(
+        Entity,
+        &mut ColorBlindnessPreview,
+        &mut ColorBlindnessCamera,
+    )>,
+) {
+    for (entity, mut preview, mut camera) in &mut previews {
+        if preview.tick(time.delta()) {
+            camera.mode = preview.previous_mode.clone();
+            camera.enabled = preview.previous_enabled;
+            commands.entity(entity).remove::<ColorBlindnessPreview>();
+        }
+    }
+}
+
+/// Flips `enabled` on the [`ColorBlindnessCamera`] on the same entity every `interval`,
+/// making the effect obvious in demo videos and presentations.
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_color_blindness::*;
+/// let mut toggle = ColorBlindnessAutoToggle::new(Duration::from_secs(2));
+///
+/// assert!(!toggle.tick(Duration::from_secs(1)));
+/// assert!(toggle.tick(Duration::from_secs(1)));
+///
+/// // stopped toggles never flip
+/// toggle.stop();
+/// assert!(!toggle.tick(Duration::from_secs(5)));
+///
+/// // and start over when started again
+/// toggle.start();
+/// assert!(!toggle.tick(Duration::from_secs(1)));
+/// assert!(toggle.tick(Duration::from_secs(1)));
+/// ```
+#[derive(Component, Debug)]
+pub struct ColorBlindnessAutoToggle {
+    timer: Timer,
+    running: bool,
+}
+
+impl ColorBlindnessAutoToggle {
+    /// Creates a running auto toggle, flipping every `interval`.
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            timer: Timer::new(interval, TimerMode::Repeating),
+            running: true,
+        }
+    }
+
+    /// Starts flipping again, from the beginning of the interval.
+    pub fn start(&mut self) {
+        self.timer.reset();
+        self.running = true;
+    }
+
+    /// Stops flipping, leaving `enabled` as it currently is.
+    pub fn stop(&mut self) {
+        self.running = false;
+    }
+
+    /// Returns whether the auto toggle is currently flipping.
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
+    /// Advances the timer by `delta`, returning `true` if `enabled` should be flipped.
+    pub fn tick(&mut self, delta: Duration) -> bool {
+        // an odd number of flips in a single tick is a single flip
+        self.running && self.timer.tick(delta).times_finished_this_tick() % 2 == 1
+    }
+}
+
+/// flips `enabled` on cameras with a running `ColorBlindnessAutoToggle`
+pub(crate) fn auto_toggle(
+    time: Res<Time>,
+    mut cameras: Query<(&mut ColorBlindnessAutoToggle, &mut ColorBlindnessCamera)>,
+) {
+    for (mut toggle, mut camera) in &mut cameras {
+        if toggle.tick(time.delta()) {
+            camera.enabled = !camera.enabled;
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{ColorBlindnessCamera, ColorBlindnessMode};
+
+/// Opt-in plugin tracking how long each mode has been in use, in [`ColorBlindnessTelemetry`].
+///
+/// Useful for finding out which accessibility settings players actually use.
+pub struct ColorBlindnessTelemetryPlugin;
+
+impl Plugin for ColorBlindnessTelemetryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ColorBlindnessTelemetry>()
+            .add_systems(Update, record_telemetry);
+    }
+}
+
+/// Cumulative time spent in each mode, updated by [`ColorBlindnessTelemetryPlugin`].
+///
+/// Disabled cameras count as [`ColorBlindnessMode::Normal`], since that's what the player sees.
+/// When several cameras use the same mode at once, the time is only counted once.
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_color_blindness::*;
+/// let mut telemetry = ColorBlindnessTelemetry::default();
+/// telemetry.record(&ColorBlindnessMode::Protanopia, Duration::from_secs(2));
+/// telemetry.record(&ColorBlindnessMode::Protanopia, Duration::from_secs(3));
+///
+/// assert_eq!(telemetry.time_in(&ColorBlindnessMode::Protanopia), Duration::from_secs(5));
+/// assert_eq!(telemetry.time_in(&ColorBlindnessMode::Tritanopia), Duration::ZERO);
+/// ```
+#[derive(Resource, Default, Debug, Clone)]
+pub struct ColorBlindnessTelemetry {
+    times: Vec<(ColorBlindnessMode, Duration)>,
+}
+
+impl ColorBlindnessTelemetry {
+    /// Adds `time` to the time spent in `mode`.
+    pub fn record(&mut self, mode: &ColorBlindnessMode, time: Duration) {
+        match self.times.iter_mut().find(|(m, _)| m == mode) {
+            Some((_, total)) => *total += time,
+            None => self.times.push((mode.clone(), time)),
+        }
+    }
+
+    /// Returns the total time spent in `mode`.
+    pub fn time_in(&self, mode: &ColorBlindnessMode) -> Duration {
+        self.times
+            .iter()
+            .find(|(m, _)| m == mode)
+            .map(|(_, time)| *time)
+            .unwrap_or_default()
+    }
+
+    /// Iterates over every mode that has been used, with the total time spent in it.
+    pub fn iter(&self) -> impl Iterator<Item = (&ColorBlindnessMode, Duration)> {
+        self.times.iter().map(|(mode, time)| (mode, *time))
+    }
+
+    /// Clears all recorded times.
+    pub fn reset(&mut self) {
+        self.times.clear();
+    }
+}
+
+fn record_telemetry(
+    time: Res<Time>,
+    cameras: Query<&ColorBlindnessCamera>,
+    mut telemetry: ResMut<ColorBlindnessTelemetry>,
+) {
+    let mut seen: Vec<&ColorBlindnessMode> = vec![];
+    for camera in &cameras {
+        let mode = if camera.enabled {
+            &camera.mode
+        } else {
+            &ColorBlindnessMode::Normal
+        };
+        if !seen.contains(&mode) {
+            seen.push(mode);
+            telemetry.record(mode, time.delta());
+        }
+    }
+}
@@ -0,0 +1,108 @@
+//! Checks that the pass is set up the same way for cameras configured differently.
+//!
+//! How the image is drawn needs a GPU, so these only check the main world side,
+//! and what's extracted to the render world.
+
+mod common;
+
+use bevy::{
+    core_pipeline::{clear_color::ClearColorConfig, tonemapping::Tonemapping},
+    prelude::*,
+    render::extract_component::ExtractComponent,
+};
+use bevy_color_blindness::*;
+
+use common::{headless_app, post_process_percentages};
+
+fn color_blindness() -> ColorBlindnessCamera {
+    ColorBlindnessCamera {
+        mode: ColorBlindnessMode::Deuteranopia,
+        enabled: true,
+        ..default()
+    }
+}
+
+fn is_extracted(app: &App, camera: Entity) -> bool {
+    let settings = app.world.get::<ColorBlindnessPostProcess>(camera).unwrap();
+    let camera = app.world.get::<ColorBlindnessCamera>(camera).unwrap();
+    ColorBlindnessPostProcess::extract_component((settings, camera)).is_some()
+}
+
+#[test]
+fn tonemapping_does_not_change_the_post_process() {
+    let mut app = headless_app(ColorBlindnessPlugin);
+    let tonemapped = app
+        .world
+        .spawn((Camera3dBundle::default(), color_blindness()))
+        .id();
+    let not_tonemapped = app
+        .world
+        .spawn((
+            Camera3dBundle {
+                tonemapping: Tonemapping::None,
+                ..default()
+            },
+            color_blindness(),
+        ))
+        .id();
+    app.update();
+
+    assert_ne!(
+        app.world.get::<Tonemapping>(tonemapped),
+        Some(&Tonemapping::None)
+    );
+    assert_eq!(
+        post_process_percentages(&app, tonemapped),
+        post_process_percentages(&app, not_tonemapped)
+    );
+    assert!(is_extracted(&app, tonemapped));
+    assert!(is_extracted(&app, not_tonemapped));
+}
+
+#[test]
+fn cameras_drawn_over_another_camera_get_the_post_process() {
+    let mut app = headless_app(ColorBlindnessPlugin);
+    let background = app
+        .world
+        .spawn(Camera3dBundle {
+            camera_3d: Camera3d {
+                clear_color: ClearColorConfig::Custom(Color::rgba(0.2, 0.4, 0.6, 0.0)),
+                ..default()
+            },
+            ..default()
+        })
+        .id();
+    let overlay = app
+        .world
+        .spawn((
+            Camera3dBundle {
+                camera: Camera {
+                    order: 1,
+                    ..default()
+                },
+                camera_3d: Camera3d {
+                    clear_color: ClearColorConfig::None,
+                    ..default()
+                },
+                ..default()
+            },
+            color_blindness(),
+        ))
+        .id();
+    let standalone = app
+        .world
+        .spawn((Camera3dBundle::default(), color_blindness()))
+        .id();
+    app.update();
+
+    assert!(app
+        .world
+        .get::<ColorBlindnessPostProcess>(background)
+        .is_none());
+    assert!(app.world.resource::<ColorBlindnessActive>().0);
+    assert_eq!(
+        post_process_percentages(&app, overlay),
+        post_process_percentages(&app, standalone)
+    );
+    assert!(is_extracted(&app, overlay));
+}
@@ -0,0 +1,122 @@
+//! Helpers shared by the integration tests.
+
+// every test binary compiles this module, but none of them uses all of it
+#![allow(dead_code)]
+
+use std::time::Duration;
+
+use bevy::{
+    app::SubApp,
+    core_pipeline::{core_2d, core_3d},
+    ecs::system::CommandQueue,
+    prelude::*,
+    render::{
+        render_graph::{EmptyNode, RenderGraph},
+        RenderApp,
+    },
+};
+use bevy_color_blindness::*;
+
+/// Returns an app without a renderer, with `plugin` added.
+///
+/// Only the main world side of the plugin runs, which is what these tests check.
+pub fn headless_app(plugin: impl Plugin) -> App {
+    let mut app = App::new();
+    app.init_resource::<Time>().add_plugin(plugin);
+    app
+}
+
+/// Returns an app with a render app that only has the parts of the core 2d and 3d graphs
+/// the pass is ordered against, with `plugin` added.
+///
+/// There's no GPU, so the app can't be updated or finished, but the graphs can be inspected
+/// once the plugin has been built.
+pub fn render_graph_app(plugin: impl Plugin) -> App {
+    let mut render_app = App::empty();
+    let mut graph = RenderGraph::default();
+    for (name, tonemapping, end) in [
+        (
+            core_2d::graph::NAME,
+            core_2d::graph::node::TONEMAPPING,
+            core_2d::graph::node::END_MAIN_PASS_POST_PROCESSING,
+        ),
+        (
+            core_3d::graph::NAME,
+            core_3d::graph::node::TONEMAPPING,
+            core_3d::graph::node::END_MAIN_PASS_POST_PROCESSING,
+        ),
+    ] {
+        let mut sub_graph = RenderGraph::default();
+        sub_graph.add_node(tonemapping, EmptyNode);
+        sub_graph.add_node(end, EmptyNode);
+        graph.add_sub_graph(name, sub_graph);
+    }
+    render_app.insert_resource(graph);
+
+    let mut app = App::new();
+    app.insert_sub_app(RenderApp, SubApp::new(render_app, |_, _| {}));
+    app.init_resource::<Time>().add_plugin(plugin);
+    app
+}
+
+/// Returns the core graph called `name` of an app created with [`render_graph_app`].
+pub fn core_graph<'a>(app: &'a App, name: &str) -> &'a RenderGraph {
+    app.sub_app(RenderApp)
+        .world
+        .resource::<RenderGraph>()
+        .get_sub_graph(name)
+        .unwrap()
+}
+
+/// Returns whether `graph` has an edge making `first` run before `then`.
+pub fn runs_before(graph: &RenderGraph, first: &str, then: &str) -> bool {
+    let first = graph.get_node_id(first).unwrap();
+    graph
+        .get_node_state(then)
+        .unwrap()
+        .edges
+        .input_edges()
+        .iter()
+        .any(|edge| edge.get_input_node() == first)
+}
+
+/// Spawns a camera simulating `mode`.
+pub fn spawn_camera(app: &mut App, mode: ColorBlindnessMode) -> Entity {
+    app.world
+        .spawn(ColorBlindnessCamera {
+            mode,
+            enabled: true,
+            ..default()
+        })
+        .id()
+}
+
+/// Returns the percentages the post-process of `camera` is currently applying.
+pub fn post_process_percentages(app: &App, camera: Entity) -> ColorBlindnessPercentages {
+    app.world
+        .get::<ColorBlindnessPostProcess>(camera)
+        .expect("camera has no ColorBlindnessPostProcess")
+        .percentages()
+}
+
+/// Advances the app's `Time` by `delta`, to be picked up by the next update.
+pub fn advance_time(app: &mut App, delta: Duration) {
+    let mut time = app.world.resource_mut::<Time>();
+    let last_update = match time.last_update() {
+        Some(last_update) => last_update,
+        None => {
+            // the first update never has a delta
+            let startup = time.startup();
+            time.update_with_instant(startup);
+            startup
+        }
+    };
+    time.update_with_instant(last_update + delta);
+}
+
+/// Queues commands with `f` and applies them to the app's world right away.
+pub fn run_commands(app: &mut App, f: impl FnOnce(&mut Commands)) {
+    let mut queue = CommandQueue::default();
+    f(&mut Commands::new(&mut queue, &app.world));
+    queue.apply(&mut app.world);
+}
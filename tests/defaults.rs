@@ -0,0 +1,104 @@
+//! Checks how cameras pick up the app-wide [`ColorBlindnessDefaults`].
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_color_blindness::*;
+
+use common::headless_app;
+
+fn app_with_default_severity(severity: f32) -> App {
+    let mut app = headless_app(
+        ColorBlindnessPlugin::builder()
+            .default_severity(severity)
+            .build(),
+    );
+    app.update();
+    app
+}
+
+fn severity(app: &App, camera: Entity) -> f32 {
+    app.world
+        .get::<ColorBlindnessCamera>(camera)
+        .unwrap()
+        .severity
+}
+
+#[test]
+fn cameras_built_from_the_defaults_inherit_them() {
+    let mut app = app_with_default_severity(0.5);
+    let camera = app.world.resource::<ColorBlindnessDefaults>().camera();
+    let camera = app.world.spawn(camera).id();
+    app.update();
+
+    assert_eq!(severity(&app, camera), 0.5);
+}
+
+#[test]
+fn cameras_can_override_the_defaults() {
+    let mut app = app_with_default_severity(0.5);
+    let camera = ColorBlindnessCamera {
+        severity: 0.8,
+        ..app.world.resource::<ColorBlindnessDefaults>().camera()
+    };
+    let camera = app.world.spawn(camera).id();
+    app.update();
+
+    assert_eq!(severity(&app, camera), 0.8);
+}
+
+#[test]
+fn an_explicit_full_severity_is_kept() {
+    let mut app = app_with_default_severity(0.5);
+    let camera = app
+        .world
+        .spawn(ColorBlindnessCamera {
+            mode: ColorBlindnessMode::Protanopia,
+            enabled: true,
+            severity: 1.0,
+            ..default()
+        })
+        .id();
+    app.update();
+
+    assert_eq!(severity(&app, camera), 1.0);
+    assert_eq!(
+        app.world
+            .get::<ColorBlindnessPostProcess>(camera)
+            .unwrap()
+            .percentages(),
+        ColorBlindnessMode::Protanopia.percentages()
+    );
+}
+
+#[test]
+fn presets_are_not_overwritten() {
+    let mut app = app_with_default_severity(0.5);
+    let mut camera = ColorBlindnessCamera::default();
+    // at full severity, which is what the defaults used to overwrite
+    ColorBlindnessPreset::TypicalDeuteranomaly
+        .settings()
+        .apply_to(&mut camera);
+    let camera = app.world.spawn(camera).id();
+    app.update();
+
+    assert_eq!(severity(&app, camera), 1.0);
+}
+
+#[test]
+fn cameras_built_from_the_defaults_inherit_the_mode() {
+    let mut app = headless_app(
+        ColorBlindnessPlugin::builder()
+            .default_mode(ColorBlindnessMode::Deuteranopia)
+            .build(),
+    );
+    let camera = app.world.resource::<ColorBlindnessDefaults>().camera();
+    assert!(!camera.enabled);
+    let camera = app.world.spawn(camera).id();
+    app.update();
+
+    assert_eq!(
+        app.world.get::<ColorBlindnessCamera>(camera).unwrap().mode,
+        ColorBlindnessMode::Deuteranopia
+    );
+}
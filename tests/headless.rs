@@ -0,0 +1,218 @@
+//! Checks the main world side of the plugin, which runs without a renderer.
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_color_blindness::*;
+
+use common::{headless_app, post_process_percentages, spawn_camera};
+
+#[test]
+fn example_app_runs_headlessly() {
+    // a copy of the `main` example's camera and input handling, minus rendering
+    fn setup(mut commands: Commands) {
+        commands.spawn((
+            Camera3dBundle::default(),
+            ColorBlindnessCamera {
+                mode: ColorBlindnessMode::Deuteranopia,
+                enabled: false,
+                ..default()
+            },
+        ));
+    }
+
+    fn change_mode(input: Res<Input<KeyCode>>, mut cameras: Query<&mut ColorBlindnessCamera>) {
+        for mut camera in &mut cameras {
+            if input.just_pressed(KeyCode::N) {
+                camera.mode.cycle();
+            }
+            camera.enabled = input.pressed(KeyCode::Space);
+        }
+    }
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .init_resource::<Input<KeyCode>>()
+        .add_plugin(ColorBlindnessPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, change_mode);
+
+    app.update();
+    let mut input = app.world.resource_mut::<Input<KeyCode>>();
+    input.press(KeyCode::N);
+    input.press(KeyCode::Space);
+    app.update();
+
+    let mut cameras = app
+        .world
+        .query::<(&ColorBlindnessCamera, &ColorBlindnessPostProcess)>();
+    let (camera, post_process) = cameras.single(&app.world);
+    assert_eq!(camera.mode, ColorBlindnessMode::Deuteranomaly);
+    assert!(camera.enabled);
+    assert_eq!(post_process.percentages(), camera.percentages());
+}
+
+#[test]
+fn post_process_is_inserted_on_spawn_with_fixed_update() {
+    let mut app = headless_app(
+        ColorBlindnessPlugin::builder()
+            .schedule(ColorBlindnessSchedule::FixedUpdate)
+            .build(),
+    );
+    let camera = spawn_camera(&mut app, ColorBlindnessMode::Protanopia);
+    app.update();
+
+    assert_eq!(
+        post_process_percentages(&app, camera),
+        ColorBlindnessMode::Protanopia.percentages()
+    );
+}
+
+#[test]
+fn changes_made_in_update_are_applied_the_same_frame() {
+    /// what `apply_change` sets on every camera during the next `Update`
+    #[derive(Resource, Default)]
+    struct Change(Option<(bool, ColorBlindnessMode)>);
+
+    fn apply_change(mut change: ResMut<Change>, mut cameras: Query<&mut ColorBlindnessCamera>) {
+        let Some((enabled, mode)) = change.0.take() else {
+            return;
+        };
+        for mut camera in &mut cameras {
+            camera.enabled = enabled;
+            camera.mode = mode.clone();
+        }
+    }
+
+    fn assert_applied(app: &App, camera: Entity, enabled: bool, mode: ColorBlindnessMode) {
+        assert_eq!(post_process_percentages(app, camera), mode.percentages());
+        assert_eq!(app.world.resource::<ColorBlindnessActive>().0, enabled);
+        let settings = app.world.get::<ColorBlindnessCamera>(camera).unwrap();
+        assert_eq!(settings.is_effect_active(), enabled);
+    }
+
+    let mut app = headless_app(ColorBlindnessPlugin);
+    app.init_resource::<Change>()
+        .add_systems(Update, apply_change);
+    let camera = app
+        .world
+        .spawn((
+            Camera::default(),
+            ColorBlindnessCamera {
+                mode: ColorBlindnessMode::Protanopia,
+                enabled: true,
+                ..default()
+            },
+        ))
+        .id();
+    app.update();
+    assert_applied(&app, camera, true, ColorBlindnessMode::Protanopia);
+
+    // on to off
+    app.insert_resource(Change(Some((false, ColorBlindnessMode::Deuteranopia))));
+    app.update();
+    assert_applied(&app, camera, false, ColorBlindnessMode::Deuteranopia);
+
+    // off to on
+    app.insert_resource(Change(Some((true, ColorBlindnessMode::Tritanopia))));
+    app.update();
+    assert_applied(&app, camera, true, ColorBlindnessMode::Tritanopia);
+}
+
+#[test]
+fn post_process_follows_mode_changes() {
+    let mut app = headless_app(ColorBlindnessPlugin);
+    let camera = spawn_camera(&mut app, ColorBlindnessMode::Normal);
+    app.update();
+
+    app.world
+        .get_mut::<ColorBlindnessCamera>(camera)
+        .unwrap()
+        .mode = ColorBlindnessMode::Deuteranopia;
+    app.update();
+
+    assert_eq!(
+        post_process_percentages(&app, camera),
+        ColorBlindnessMode::Deuteranopia.percentages()
+    );
+}
+
+#[test]
+fn severity_changes_update_the_post_process_only_when_changed() {
+    #[derive(Resource, Default)]
+    struct Updates(usize);
+
+    fn count_updates(
+        mut updates: ResMut<Updates>,
+        changed: Query<(), Changed<ColorBlindnessPostProcess>>,
+    ) {
+        updates.0 += changed.iter().count();
+    }
+
+    let mut app = headless_app(ColorBlindnessPlugin);
+    app.init_resource::<Updates>()
+        .add_systems(Last, count_updates);
+    let camera = spawn_camera(&mut app, ColorBlindnessMode::Protanopia);
+    app.update();
+    assert_eq!(app.world.resource::<Updates>().0, 1);
+
+    // nothing changed, so nothing is recomputed
+    app.update();
+    assert_eq!(app.world.resource::<Updates>().0, 1);
+
+    app.world
+        .get_mut::<ColorBlindnessCamera>(camera)
+        .unwrap()
+        .severity = 0.5;
+    app.update();
+    assert_eq!(app.world.resource::<Updates>().0, 2);
+
+    let expected = app
+        .world
+        .get::<ColorBlindnessCamera>(camera)
+        .unwrap()
+        .percentages();
+    assert_eq!(post_process_percentages(&app, camera), expected);
+    assert_ne!(expected, ColorBlindnessMode::Protanopia.percentages());
+}
+
+#[test]
+fn active_tracks_cameras_changing_the_image() {
+    let mut app = headless_app(ColorBlindnessPlugin);
+    let camera = app
+        .world
+        .spawn((Camera::default(), ColorBlindnessCamera::default()))
+        .id();
+    app.update();
+    assert!(!app.world.resource::<ColorBlindnessActive>().0);
+
+    let mut settings = app.world.get_mut::<ColorBlindnessCamera>(camera).unwrap();
+    settings.enabled = true;
+    settings.mode = ColorBlindnessMode::Tritanopia;
+    app.update();
+    assert!(app.world.resource::<ColorBlindnessActive>().0);
+
+    app.world.get_mut::<Camera>(camera).unwrap().is_active = false;
+    app.update();
+    assert!(!app.world.resource::<ColorBlindnessActive>().0);
+}
+
+#[test]
+fn percentages_hook_is_applied() {
+    let mut app = headless_app(ColorBlindnessPlugin);
+    app.insert_resource(ColorBlindnessPercentagesHook::new(|percentages| {
+        std::mem::swap(&mut percentages.red, &mut percentages.blue);
+    }));
+    let camera = spawn_camera(&mut app, ColorBlindnessMode::Protanopia);
+    app.update();
+
+    let protanopia = ColorBlindnessMode::Protanopia.percentages();
+    assert_eq!(post_process_percentages(&app, camera).red, protanopia.blue);
+}
+
+#[test]
+#[should_panic]
+fn the_pass_can_only_be_added_once() {
+    let mut app = headless_app(ColorBlindnessPlugin);
+    app.add_plugin(ColorBlindnessPlugin::builder().build());
+}
@@ -0,0 +1,59 @@
+//! Checks the simulation matrices against properties that don't come from the matrices themselves.
+
+use bevy::prelude::*;
+use bevy_color_blindness::*;
+
+/// Returns the matrix `percentages` apply to linear RGB colors.
+fn matrix(percentages: ColorBlindnessPercentages) -> Mat3 {
+    Mat3::from_cols(percentages.red, percentages.green, percentages.blue).transpose()
+}
+
+/// Returns how much `mode` scales color differences along `cone`, a direction in LMS space.
+fn cone_scale(mode: ColorBlindnessMode, cone: Vec3) -> f32 {
+    // linear sRGB to LMS, from Viénot, Brettel and Mollon (1999)
+    let lms_from_rgb = Mat3::from_cols(
+        Vec3::new(17.8824, 43.5161, 4.11935),
+        Vec3::new(3.45565, 27.1554, 3.86714),
+        Vec3::new(0.0299566, 0.184309, 1.46709),
+    )
+    .transpose();
+    let difference = lms_from_rgb.inverse() * cone;
+    (matrix(mode.percentages()) * difference).length() / difference.length()
+}
+
+#[test]
+fn tritanopia_loses_most_of_the_s_cone_signal() {
+    assert!(cone_scale(ColorBlindnessMode::Tritanopia, Vec3::Z) < 0.25);
+    // L and M cone differences are kept
+    assert!(cone_scale(ColorBlindnessMode::Tritanopia, Vec3::X) > 0.9);
+    assert!(cone_scale(ColorBlindnessMode::Tritanopia, Vec3::Y) > 0.9);
+}
+
+#[test]
+fn tritanopia_tells_red_and_green_apart() {
+    let percentages = ColorBlindnessMode::Tritanopia.percentages();
+    assert!(
+        delta_e(
+            percentages.apply(Color::RED),
+            percentages.apply(Color::GREEN)
+        ) > 50.0
+    );
+}
+
+#[test]
+fn dichromacies_and_anomalous_trichromacies_keep_white_white() {
+    for mode in [
+        ColorBlindnessMode::Protanopia,
+        ColorBlindnessMode::Protanomaly,
+        ColorBlindnessMode::Deuteranopia,
+        ColorBlindnessMode::Deuteranomaly,
+        ColorBlindnessMode::Tritanopia,
+        ColorBlindnessMode::Tritanomaly,
+    ] {
+        let white = Vec4::from(mode.percentages().apply(Color::WHITE).as_rgba_f32());
+        assert!(
+            (white - Vec4::ONE).abs().max_element() < 1e-4,
+            "{mode:?} turns white into {white}"
+        );
+    }
+}
@@ -0,0 +1,89 @@
+//! Checks the palette helpers.
+
+use bevy::prelude::*;
+use bevy_color_blindness::*;
+
+#[test]
+fn anomalous_modes_are_checked_on_their_own() {
+    // far enough apart for normal vision and every dichromacy,
+    // but closer than 10 for protanomaly and deuteranomaly
+    let colors = [
+        Color::rgb_linear(0.792, 0.743, 0.913),
+        Color::rgb_linear(0.592, 0.754, 0.976),
+    ];
+    for mode in [
+        ColorBlindnessMode::Normal,
+        ColorBlindnessMode::Protanopia,
+        ColorBlindnessMode::Deuteranopia,
+        ColorBlindnessMode::Tritanopia,
+    ] {
+        let percentages = mode.percentages();
+        let distance = delta_e(percentages.apply(colors[0]), percentages.apply(colors[1]));
+        assert!(distance >= 10.0, "{mode:?}: {distance}");
+    }
+
+    assert!(!is_palette_safe(&colors, 10.0));
+}
+
+/// Returns the smallest [`delta_e`] between any two of `colors`, under any of `modes` or normal vision.
+fn smallest_distance(colors: &[Color], modes: &[ColorBlindnessMode]) -> f32 {
+    let mut smallest = f32::INFINITY;
+    for mode in modes.iter().chain([&ColorBlindnessMode::Normal]) {
+        let percentages = mode.percentages();
+        for (i, a) in colors.iter().enumerate() {
+            for b in &colors[i + 1..] {
+                smallest = smallest.min(delta_e(percentages.apply(*a), percentages.apply(*b)));
+            }
+        }
+    }
+    smallest
+}
+
+#[test]
+fn suggested_palettes_are_deterministic() {
+    let modes = [
+        ColorBlindnessMode::Deuteranopia,
+        ColorBlindnessMode::Protanopia,
+    ];
+    assert_eq!(suggest_palette(6, &modes), suggest_palette(6, &modes));
+}
+
+#[test]
+fn suggested_palettes_beat_the_first_candidates() {
+    let modes = [
+        ColorBlindnessMode::Deuteranopia,
+        ColorBlindnessMode::Protanopia,
+    ];
+    let palette = suggest_palette(4, &modes);
+    assert_eq!(palette.len(), 4);
+
+    // the first candidates are the darkest, least saturated ones, 15° of hue apart
+    let naive: Vec<Color> = (0..4)
+        .map(|hue| Color::hsl(hue as f32 * 15.0, 0.5, 0.3))
+        .collect();
+    assert!(smallest_distance(&palette, &modes) > smallest_distance(&naive, &modes));
+}
+
+#[test]
+fn achromatopsia_has_no_confusion_partner() {
+    let gray = Color::rgb(0.5, 0.5, 0.5);
+    assert_eq!(
+        confusion_partner(&ColorBlindnessMode::Achromatopsia, gray),
+        None
+    );
+    assert_eq!(
+        confusion_partner(&ColorBlindnessMode::Monochromacy { residual: 0.0 }, gray),
+        None
+    );
+}
+
+#[test]
+fn monochromacy_with_a_residual_has_a_confusion_partner() {
+    let mode = ColorBlindnessMode::Monochromacy { residual: 0.1 };
+    let gray = Color::rgb(0.5, 0.5, 0.5);
+    let partner = confusion_partner(&mode, gray).unwrap();
+
+    let percentages = mode.percentages();
+    assert!(delta_e(percentages.apply(gray), percentages.apply(partner)) < 2.3);
+    assert!(delta_e(gray, partner) > 10.0);
+}
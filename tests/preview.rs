@@ -0,0 +1,87 @@
+//! Checks previews started with [`ColorBlindnessCommandsExt::preview_color_blindness`].
+
+mod common;
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_color_blindness::*;
+
+use common::{advance_time, headless_app, run_commands};
+
+fn preview(app: &mut App, camera: Entity, mode: ColorBlindnessMode) {
+    run_commands(app, |commands| {
+        commands.preview_color_blindness(camera, mode, Duration::from_secs(3));
+    });
+}
+
+fn camera_state(app: &App, camera: Entity) -> (ColorBlindnessMode, bool) {
+    let camera = app.world.get::<ColorBlindnessCamera>(camera).unwrap();
+    (camera.mode.clone(), camera.enabled)
+}
+
+#[test]
+fn preview_restores_the_camera_and_removes_itself() {
+    let mut app = headless_app(ColorBlindnessPlugin);
+    let camera = app
+        .world
+        .spawn(ColorBlindnessCamera {
+            mode: ColorBlindnessMode::Protanopia,
+            enabled: false,
+            ..default()
+        })
+        .id();
+    app.update();
+
+    preview(&mut app, camera, ColorBlindnessMode::Tritanopia);
+    advance_time(&mut app, Duration::from_secs(2));
+    app.update();
+    assert_eq!(
+        camera_state(&app, camera),
+        (ColorBlindnessMode::Tritanopia, true)
+    );
+    assert!(app.world.get::<ColorBlindnessPreview>(camera).is_some());
+
+    advance_time(&mut app, Duration::from_secs(1));
+    app.update();
+    assert_eq!(
+        camera_state(&app, camera),
+        (ColorBlindnessMode::Protanopia, false)
+    );
+    assert!(app.world.get::<ColorBlindnessPreview>(camera).is_none());
+}
+
+#[test]
+fn previewing_again_restarts_and_restores_the_original_state() {
+    let mut app = headless_app(ColorBlindnessPlugin);
+    let camera = app
+        .world
+        .spawn(ColorBlindnessCamera {
+            mode: ColorBlindnessMode::Protanopia,
+            enabled: false,
+            ..default()
+        })
+        .id();
+    app.update();
+
+    preview(&mut app, camera, ColorBlindnessMode::Tritanopia);
+    advance_time(&mut app, Duration::from_secs(2));
+    app.update();
+
+    preview(&mut app, camera, ColorBlindnessMode::Deuteranopia);
+    // past the end of the first preview, but not the second one
+    advance_time(&mut app, Duration::from_secs(2));
+    app.update();
+    assert_eq!(
+        camera_state(&app, camera),
+        (ColorBlindnessMode::Deuteranopia, true)
+    );
+
+    advance_time(&mut app, Duration::from_secs(1));
+    app.update();
+    assert_eq!(
+        camera_state(&app, camera),
+        (ColorBlindnessMode::Protanopia, false)
+    );
+    assert!(app.world.get::<ColorBlindnessPreview>(camera).is_none());
+}
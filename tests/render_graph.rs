@@ -0,0 +1,76 @@
+//! Checks where the pass is added to the core graphs.
+//!
+//! These only build the render app, since running the graph needs a GPU.
+
+mod common;
+
+use bevy::{
+    core_pipeline::{core_2d, core_3d},
+    prelude::*,
+    render::{
+        render_graph::{EmptyNode, RenderGraphApp},
+        RenderApp,
+    },
+};
+use bevy_color_blindness::*;
+
+use common::{core_graph, render_graph_app, runs_before};
+
+fn plugin() -> ConfiguredColorBlindnessPlugin {
+    // a custom shader, so the plugin doesn't need an asset server
+    ColorBlindnessPlugin::builder()
+        .shader(Handle::default())
+        .build()
+}
+
+#[test]
+fn other_post_process_nodes_can_be_chained_after_the_pass() {
+    const OTHER: &str = "other_post_process";
+
+    let mut app = render_graph_app(plugin());
+    app.sub_app_mut(RenderApp)
+        .add_render_graph_node::<EmptyNode>(core_3d::graph::NAME, OTHER)
+        .add_render_graph_edges(
+            core_3d::graph::NAME,
+            &[
+                PostProcessNode::NAME,
+                OTHER,
+                core_3d::graph::node::END_MAIN_PASS_POST_PROCESSING,
+            ],
+        );
+
+    let graph = core_graph(&app, core_3d::graph::NAME);
+    assert!(runs_before(
+        graph,
+        core_3d::graph::node::TONEMAPPING,
+        PostProcessNode::NAME
+    ));
+    assert!(runs_before(graph, PostProcessNode::NAME, OTHER));
+    assert!(runs_before(
+        graph,
+        OTHER,
+        core_3d::graph::node::END_MAIN_PASS_POST_PROCESSING
+    ));
+}
+
+#[test]
+fn the_pass_runs_after_tonemapping_in_both_graphs() {
+    let app = render_graph_app(plugin());
+
+    for (name, tonemapping, end) in [
+        (
+            core_2d::graph::NAME,
+            core_2d::graph::node::TONEMAPPING,
+            core_2d::graph::node::END_MAIN_PASS_POST_PROCESSING,
+        ),
+        (
+            core_3d::graph::NAME,
+            core_3d::graph::node::TONEMAPPING,
+            core_3d::graph::node::END_MAIN_PASS_POST_PROCESSING,
+        ),
+    ] {
+        let graph = core_graph(&app, name);
+        assert!(runs_before(graph, tonemapping, PostProcessNode::NAME));
+        assert!(runs_before(graph, PostProcessNode::NAME, end));
+    }
+}